@@ -1,8 +1,149 @@
-use syn::parse_quote;
+use syn::{meta::ParseNestedMeta, parse_quote_spanned, spanned::Spanned};
+
+/// Parses every item of a `#[builder(...)]` attribute with `parse`. An item
+/// which fails is recorded as an error and skipped, so the items after it are
+/// still checked and every problem is reported in one compile.
+fn parse_builder_items<T>(
+    attribute: &syn::Attribute,
+    mut parse: impl FnMut(&ParseNestedMeta, &mut std::vec::Vec<syn::Result<T>>) -> syn::Result<()>,
+) -> std::vec::Vec<syn::Result<T>> {
+    let mut attributes = vec![];
+
+    if !attribute.path().is_ident("builder") {
+        return attributes;
+    }
+
+    if let Err(err) = attribute.parse_nested_meta(|meta| {
+        if let Err(err) = parse(&meta, &mut attributes) {
+            attributes.push(Err(err));
+
+            while !meta.input.is_empty() && !meta.input.peek(syn::Token![,]) {
+                meta.input.parse::<proc_macro2::TokenTree>()?;
+            }
+        }
+
+        Ok(())
+    }) {
+        attributes.push(Err(err));
+    }
+
+    attributes
+}
+
+/// Parses the name of a method, given either as a bare identifier or as a
+/// string literal.
+fn parse_method_ident(input: syn::parse::ParseStream) -> syn::Result<syn::Ident> {
+    let lookahead = input.lookahead1();
+
+    if lookahead.peek(syn::LitStr) {
+        return input.parse::<syn::LitStr>()?.parse();
+    }
+
+    if lookahead.peek(syn::Ident) {
+        return input.parse();
+    }
+
+    Err(lookahead.error())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValidationStage {
+    Missing,
+    Fields,
+    Pre,
+    Cross,
+    Struct,
+}
+
+impl ValidationStage {
+    const DEFAULT_ORDER: [ValidationStage; 5] = [
+        ValidationStage::Missing,
+        ValidationStage::Fields,
+        ValidationStage::Pre,
+        ValidationStage::Cross,
+        ValidationStage::Struct,
+    ];
+
+    fn needs_every_field(self) -> bool {
+        matches!(self, ValidationStage::Cross | ValidationStage::Struct)
+    }
+}
+
+/// How `build()` gets at the values stored in the builder.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BuildMode {
+    /// `build(&self)`, cloning every value so the builder can be reused.
+    #[default]
+    Ref,
+    /// `build(self)`, moving the values out of the builder.
+    Owned,
+    /// `build(&mut self)`, taking the values out of the builder, so field
+    /// types do not need to implement `Clone`.
+    Take,
+}
+
+/// A smart pointer which `build_into` can build the target straight into.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SmartPointer {
+    Arc,
+    Rc,
+    Box,
+}
+
+/// What an `each` method with `dedup` does with an item it already holds, or
+/// for a map with an entry whose key it already holds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DuplicatePolicy {
+    /// Drop the new item, keeping the collection as it was.
+    Ignore,
+    /// Reject the new item with an error.
+    Error,
+}
 
 #[derive(Clone, Debug)]
 pub enum BuilderStructAttribute {
     Validate(syn::Path),
+    AsyncValidate(syn::Path),
+    PreValidate(syn::Path),
+    Finalize(syn::Path),
+    CrossValidate {
+        fields: std::vec::Vec<syn::Ident>,
+        with: syn::Path,
+    },
+    AtLeastOneOf(std::vec::Vec<syn::Ident>),
+    FromStr,
+    Tracing,
+    Compact,
+    ErrorModulePath,
+    DebugExpansion,
+    Typestate,
+    Build(BuildMode),
+    BuildInto(std::vec::Vec<SmartPointer>),
+    Unchecked,
+    Base,
+    Diff,
+    Checkpoint,
+    Patch,
+    New,
+    ImplDefault,
+    ConstFn,
+    UseValidator,
+    UseGarde,
+    DeferErrors,
+    TreatEmptyAsMissing,
+    ValidateOrder(std::vec::Vec<ValidationStage>),
+    ErrorDerive(std::vec::Vec<syn::Path>),
+    ErrorDisplay {
+        invalid_state: std::option::Option<syn::LitStr>,
+        invalid_field: std::option::Option<syn::LitStr>,
+        validation_failed: std::option::Option<syn::LitStr>,
+        multiple: std::option::Option<syn::LitStr>,
+    },
+    MessageFormatter(syn::Path),
+    ErrorInto {
+        target: syn::Path,
+        with: std::option::Option<syn::Path>,
+    },
 }
 
 #[derive(Clone, Debug, Default)]
@@ -13,36 +154,612 @@ impl BuilderStructAttributes {
         self.0.iter()
     }
 
-    pub fn get_validator_path(&self) -> std::option::Option<&syn::Path> {
+    pub fn get_validator_paths(&self) -> std::vec::Vec<&syn::Path> {
         self.iter()
-            .flat_map(|attribute| match attribute {
+            .filter_map(|attribute| match attribute {
                 Ok(BuilderStructAttribute::Validate(path)) => Some(path),
                 _ => None,
             })
-            .next()
+            .collect()
+    }
+
+    pub fn get_async_validator_paths(&self) -> std::vec::Vec<&syn::Path> {
+        self.iter()
+            .filter_map(|attribute| match attribute {
+                Ok(BuilderStructAttribute::AsyncValidate(path)) => Some(path),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn get_pre_validator_paths(&self) -> std::vec::Vec<&syn::Path> {
+        self.iter()
+            .filter_map(|attribute| match attribute {
+                Ok(BuilderStructAttribute::PreValidate(path)) => Some(path),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn get_finalizer_paths(&self) -> std::vec::Vec<&syn::Path> {
+        self.iter()
+            .filter_map(|attribute| match attribute {
+                Ok(BuilderStructAttribute::Finalize(path)) => Some(path),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn get_cross_validators(&self) -> std::vec::Vec<(&[syn::Ident], &syn::Path)> {
+        self.iter()
+            .filter_map(|attribute| match attribute {
+                Ok(BuilderStructAttribute::CrossValidate { fields, with }) => {
+                    Some((fields.as_slice(), with))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn get_at_least_one_of_groups(&self) -> std::vec::Vec<&[syn::Ident]> {
+        self.iter()
+            .filter_map(|attribute| match attribute {
+                Ok(BuilderStructAttribute::AtLeastOneOf(fields)) => Some(fields.as_slice()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn get_validate_order(&self) -> std::vec::Vec<ValidationStage> {
+        let mut order = self
+            .iter()
+            .find_map(|attribute| match attribute {
+                Ok(BuilderStructAttribute::ValidateOrder(stages)) => Some(stages.clone()),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        for stage in ValidationStage::DEFAULT_ORDER {
+            if !order.contains(&stage) {
+                order.push(stage);
+            }
+        }
+
+        order
+    }
+
+    pub fn get_error_derive_paths(&self) -> std::vec::Vec<&syn::Path> {
+        self.iter()
+            .filter_map(|attribute| match attribute {
+                Ok(BuilderStructAttribute::ErrorDerive(paths)) => Some(paths),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    pub fn get_error_display(
+        &self,
+    ) -> (
+        std::option::Option<&syn::LitStr>,
+        std::option::Option<&syn::LitStr>,
+        std::option::Option<&syn::LitStr>,
+        std::option::Option<&syn::LitStr>,
+    ) {
+        self.iter()
+            .find_map(|attribute| match attribute {
+                Ok(BuilderStructAttribute::ErrorDisplay {
+                    invalid_state,
+                    invalid_field,
+                    validation_failed,
+                    multiple,
+                }) => Some((
+                    invalid_state.as_ref(),
+                    invalid_field.as_ref(),
+                    validation_failed.as_ref(),
+                    multiple.as_ref(),
+                )),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_error_into_targets(
+        &self,
+    ) -> std::vec::Vec<(&syn::Path, std::option::Option<&syn::Path>)> {
+        self.iter()
+            .filter_map(|attribute| match attribute {
+                Ok(BuilderStructAttribute::ErrorInto { target, with }) => {
+                    Some((target, with.as_ref()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn get_message_formatter_path(&self) -> std::option::Option<&syn::Path> {
+        self.iter().find_map(|attribute| match attribute {
+            Ok(BuilderStructAttribute::MessageFormatter(path)) => Some(path),
+            _ => None,
+        })
+    }
+
+    pub fn is_from_str(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::FromStr)))
+    }
+
+    pub fn is_tracing(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::Tracing)))
+    }
+
+    pub fn is_use_validator(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::UseValidator)))
+    }
+
+    pub fn is_use_garde(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::UseGarde)))
+    }
+
+    pub fn is_defer_errors(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::DeferErrors)))
+    }
+
+    pub fn is_treat_empty_as_missing(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::TreatEmptyAsMissing)))
+    }
+
+    pub fn is_error_module_path(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::ErrorModulePath)))
+    }
+
+    pub fn is_debug_expansion(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::DebugExpansion)))
+    }
+
+    pub fn is_compact(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::Compact)))
+    }
+
+    pub fn get_build_mode(&self) -> BuildMode {
+        self.iter()
+            .find_map(|attr| match attr {
+                Ok(BuilderStructAttribute::Build(mode)) => Some(*mode),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_build_pointers(&self) -> std::vec::Vec<SmartPointer> {
+        let listed = self
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Ok(BuilderStructAttribute::BuildInto(pointers)) => Some(pointers),
+                _ => None,
+            })
+            .flatten();
+
+        let mut pointers = vec![];
+        for pointer in listed {
+            if !pointers.contains(pointer) {
+                pointers.push(*pointer);
+            }
+        }
+
+        pointers
+    }
+
+    pub fn is_const_fn(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::ConstFn)))
+    }
+
+    pub fn is_impl_default(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::ImplDefault)))
+    }
+
+    pub fn is_new(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::New)))
+    }
+
+    pub fn is_patch(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::Patch)))
+    }
+
+    pub fn is_checkpoint(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::Checkpoint)))
+    }
+
+    pub fn is_diff(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::Diff)))
+    }
+
+    pub fn is_base(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::Base)))
+    }
+
+    pub fn is_unchecked(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::Unchecked)))
+    }
+
+    pub fn is_typestate(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderStructAttribute::Typestate)))
     }
 }
 
 impl From<syn::Attribute> for BuilderStructAttributes {
     fn from(value: syn::Attribute) -> Self {
-        let mut attributes = vec![];
+        let attributes = parse_builder_items(&value, |meta, attributes| {
+            if meta.path.is_ident("validate") {
+                let value = meta.value()?;
+                let path: syn::Path = value.parse()?;
+
+                attributes.push(Ok(BuilderStructAttribute::Validate(path)));
 
-        if value.path().is_ident("builder") {
-            if let Err(err) = value.parse_nested_meta(|meta| {
-                if meta.path.is_ident("validate") {
-                    let value = meta.value()?;
-                    let path: syn::Path = value.parse()?;
+                return Ok(());
+            }
 
-                    attributes.push(Ok(BuilderStructAttribute::Validate(path)));
+            if meta.path.is_ident("async_validate") {
+                let value = meta.value()?;
+                let path: syn::Path = value.parse()?;
 
-                    return Ok(());
-                }
+                attributes.push(Ok(BuilderStructAttribute::AsyncValidate(path)));
 
-                Err(meta.error("builder struct attribute not recognized".to_string()))
-            }) {
-                attributes.push(Err(err));
-            };
-        }
+                return Ok(());
+            }
+
+            if meta.path.is_ident("pre_validate") {
+                let value = meta.value()?;
+                let path: syn::Path = value.parse()?;
+
+                attributes.push(Ok(BuilderStructAttribute::PreValidate(path)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("finalize") {
+                let value = meta.value()?;
+                let path: syn::Path = value.parse()?;
+
+                attributes.push(Ok(BuilderStructAttribute::Finalize(path)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("cross_validate") {
+                let mut fields = vec![];
+                let mut with = None;
+
+                meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("fields") {
+                        return meta.parse_nested_meta(|meta| {
+                            fields.push(meta.path.require_ident()?.clone());
+
+                            Ok(())
+                        });
+                    }
+
+                    if meta.path.is_ident("with") {
+                        with = Some(meta.value()?.parse()?);
+
+                        return Ok(());
+                    }
+
+                    Err(meta.error("expected `fields(...)` or `with = path`"))
+                })?;
+
+                let Some(with) = with else {
+                    return Err(meta.error("`cross_validate` requires `with = path`"));
+                };
+
+                attributes.push(Ok(BuilderStructAttribute::CrossValidate { fields, with }));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("at_least_one_of") {
+                let mut fields = vec![];
+
+                meta.parse_nested_meta(|meta| {
+                    fields.push(meta.path.require_ident()?.clone());
+
+                    Ok(())
+                })?;
+
+                attributes.push(Ok(BuilderStructAttribute::AtLeastOneOf(fields)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("validate_order") {
+                let mut stages = vec![];
+
+                meta.parse_nested_meta(|meta| {
+                    let stage = if meta.path.is_ident("missing") {
+                        ValidationStage::Missing
+                    } else if meta.path.is_ident("fields") {
+                        ValidationStage::Fields
+                    } else if meta.path.is_ident("pre") {
+                        ValidationStage::Pre
+                    } else if meta.path.is_ident("cross") {
+                        ValidationStage::Cross
+                    } else if meta.path.is_ident("struct") {
+                        ValidationStage::Struct
+                    } else {
+                        return Err(meta.error(
+                            "expected one of `missing`, `fields`, `pre`, `cross`, or `struct`",
+                        ));
+                    };
+
+                    if stages.contains(&stage) {
+                        return Err(meta.error("validation stage listed more than once"));
+                    }
+
+                    if stage.needs_every_field() && !stages.contains(&ValidationStage::Missing) {
+                        return Err(meta.error(
+                            "`cross` and `struct` run on the built value and must come after `missing`",
+                        ));
+                    }
+
+                    stages.push(stage);
+
+                    Ok(())
+                })?;
+
+                attributes.push(Ok(BuilderStructAttribute::ValidateOrder(stages)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("error_derive") {
+                let mut paths = vec![];
+
+                meta.parse_nested_meta(|meta| {
+                    paths.push(meta.path);
+
+                    Ok(())
+                })?;
+
+                attributes.push(Ok(BuilderStructAttribute::ErrorDerive(paths)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("error_display") {
+                let mut invalid_state = None;
+                let mut invalid_field = None;
+                let mut validation_failed = None;
+                let mut multiple = None;
+
+                meta.parse_nested_meta(|meta| {
+                    let format = if meta.path.is_ident("invalid_state") {
+                        &mut invalid_state
+                    } else if meta.path.is_ident("invalid_field") {
+                        &mut invalid_field
+                    } else if meta.path.is_ident("validation_failed") {
+                        &mut validation_failed
+                    } else if meta.path.is_ident("multiple") {
+                        &mut multiple
+                    } else {
+                        return Err(meta.error(
+                            "expected `invalid_state`, `invalid_field`, `validation_failed`, or `multiple`",
+                        ));
+                    };
+
+                    *format = Some(meta.value()?.parse()?);
+
+                    Ok(())
+                })?;
+
+                attributes.push(Ok(BuilderStructAttribute::ErrorDisplay {
+                    invalid_state,
+                    invalid_field,
+                    validation_failed,
+                    multiple,
+                }));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("error_into") {
+                meta.parse_nested_meta(|meta| {
+                    let with = if meta.input.peek(syn::Token![=]) {
+                        Some(meta.value()?.parse()?)
+                    } else {
+                        None
+                    };
+
+                    attributes.push(Ok(BuilderStructAttribute::ErrorInto {
+                        target: meta.path,
+                        with,
+                    }));
+
+                    Ok(())
+                })?;
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("message_formatter") {
+                let value = meta.value()?;
+                let path: syn::Path = value.parse()?;
+
+                attributes.push(Ok(BuilderStructAttribute::MessageFormatter(path)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("from_str") {
+                attributes.push(Ok(BuilderStructAttribute::FromStr));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("tracing") {
+                attributes.push(Ok(BuilderStructAttribute::Tracing));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("use_validator") {
+                attributes.push(Ok(BuilderStructAttribute::UseValidator));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("use_garde") {
+                attributes.push(Ok(BuilderStructAttribute::UseGarde));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("defer_errors") {
+                attributes.push(Ok(BuilderStructAttribute::DeferErrors));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("treat_empty_as_missing") {
+                attributes.push(Ok(BuilderStructAttribute::TreatEmptyAsMissing));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("compact") {
+                attributes.push(Ok(BuilderStructAttribute::Compact));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("debug_expansion") {
+                attributes.push(Ok(BuilderStructAttribute::DebugExpansion));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("build") {
+                let value = meta.value()?;
+                let litstr: syn::LitStr = value.parse()?;
+
+                let mode = match litstr.value().as_str() {
+                    "ref" => BuildMode::Ref,
+                    "owned" => BuildMode::Owned,
+                    "take" => BuildMode::Take,
+                    _ => {
+                        return Err(syn::Error::new(
+                            litstr.span(),
+                            "expected one of `build = \"ref\"`, `build = \"owned\"`, or `build = \"take\"`",
+                        ));
+                    }
+                };
+
+                attributes.push(Ok(BuilderStructAttribute::Build(mode)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("build_into") {
+                let mut pointers = vec![];
+
+                meta.parse_nested_meta(|meta| {
+                    let pointer = if meta.path.is_ident("arc") {
+                        SmartPointer::Arc
+                    } else if meta.path.is_ident("rc") {
+                        SmartPointer::Rc
+                    } else if meta.path.is_ident("boxed") {
+                        SmartPointer::Box
+                    } else {
+                        return Err(meta.error("expected one of `arc`, `rc`, or `boxed`"));
+                    };
+
+                    pointers.push(pointer);
+
+                    Ok(())
+                })?;
+
+                attributes.push(Ok(BuilderStructAttribute::BuildInto(pointers)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("const_fn") {
+                attributes.push(Ok(BuilderStructAttribute::ConstFn));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("impl_default") {
+                attributes.push(Ok(BuilderStructAttribute::ImplDefault));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("new") {
+                attributes.push(Ok(BuilderStructAttribute::New));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("patch") {
+                attributes.push(Ok(BuilderStructAttribute::Patch));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("checkpoint") {
+                attributes.push(Ok(BuilderStructAttribute::Checkpoint));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("diff") {
+                attributes.push(Ok(BuilderStructAttribute::Diff));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("base") {
+                attributes.push(Ok(BuilderStructAttribute::Base));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("unchecked") {
+                attributes.push(Ok(BuilderStructAttribute::Unchecked));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("typestate") {
+                attributes.push(Ok(BuilderStructAttribute::Typestate));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("error_module_path") {
+                attributes.push(Ok(BuilderStructAttribute::ErrorModulePath));
+
+                return Ok(());
+            }
+
+            Err(meta.error("builder struct attribute not recognized".to_string()))
+        });
 
         Self(attributes)
     }
@@ -77,17 +794,66 @@ impl FromIterator<syn::Result<BuilderStructAttribute>> for BuilderStructAttribut
 #[derive(Debug)]
 pub enum BuilderFieldAttribute {
     Each(syn::Ident),
+    InsertWith(syn::Ident),
+    Dedup(DuplicatePolicy),
+    Capacity(syn::Expr),
+    Separator(syn::LitStr),
+    Group,
+    Sorted,
     Validate(syn::Path),
+    ValidateWithName(syn::Path),
     Default(syn::Path),
+    CacheDefault,
+    Clearable,
+    SkipFromStr,
+    Wrap,
+    Tristate,
+    ValidateItem(syn::Path),
+    DisplayErrors,
+    Check {
+        predicate: syn::Expr,
+        message: syn::LitStr,
+    },
+    Range(syn::Expr),
+    Len(syn::Expr),
+    NonEmpty,
+    Matches(syn::LitStr),
+    TreatEmptyAsMissing,
+    Required,
+    RequiredIf(syn::Ident),
+    RequiredUnless(syn::Ident),
+    Code(syn::LitStr),
+    MissingMessage(syn::LitStr),
 }
 
 impl BuilderFieldAttribute {
-    fn get_validator_path(&self) -> std::option::Option<&syn::Path> {
-        if let BuilderFieldAttribute::Validate(path) = self {
-            return Some(path);
+    /// The name and span of attributes which may only be given once per field.
+    fn unique_name_and_span(&self) -> std::option::Option<(&'static str, proc_macro2::Span)> {
+        match self {
+            BuilderFieldAttribute::Each(ident) => Some(("each", ident.span())),
+            BuilderFieldAttribute::InsertWith(ident) => Some(("insert_with", ident.span())),
+            BuilderFieldAttribute::Default(path) => Some(("default", path.span())),
+            BuilderFieldAttribute::Capacity(capacity) => Some(("capacity", capacity.span())),
+            BuilderFieldAttribute::Separator(separator) => Some(("separator", separator.span())),
+            BuilderFieldAttribute::Code(code) => Some(("code", code.span())),
+            BuilderFieldAttribute::MissingMessage(message) => {
+                Some(("missing_message", message.span()))
+            }
+            _ => None,
         }
+    }
 
-        None
+    fn is_value_validation(&self) -> bool {
+        matches!(
+            self,
+            BuilderFieldAttribute::Validate(_)
+                | BuilderFieldAttribute::ValidateWithName(_)
+                | BuilderFieldAttribute::Check { .. }
+                | BuilderFieldAttribute::Range(_)
+                | BuilderFieldAttribute::Len(_)
+                | BuilderFieldAttribute::NonEmpty
+                | BuilderFieldAttribute::Matches(_)
+        )
     }
 }
 
@@ -100,89 +866,414 @@ impl BuilderFieldAttributes {
     }
 
     pub fn get_each_ident(&self) -> Option<&syn::Ident> {
-        if let Some(BuilderFieldAttribute::Each(ident)) = self.into_iter().flatten().next() {
-            return Some(ident);
-        }
+        self.iter().find_map(|attr| match attr {
+            Ok(BuilderFieldAttribute::Each(ident)) => Some(ident),
+            _ => None,
+        })
+    }
 
-        None
+    pub fn get_insert_with_ident(&self) -> Option<&syn::Ident> {
+        self.iter().find_map(|attr| match attr {
+            Ok(BuilderFieldAttribute::InsertWith(ident)) => Some(ident),
+            _ => None,
+        })
     }
 
-    pub fn get_first_validator_path(&self) -> std::option::Option<&syn::Path> {
-        if let Some(BuilderFieldAttribute::Validate(path)) = self.into_iter().flatten().next() {
-            return Some(path);
-        }
+    pub fn get_duplicate_policy(&self) -> std::option::Option<DuplicatePolicy> {
+        self.iter().find_map(|attr| match attr {
+            Ok(BuilderFieldAttribute::Dedup(policy)) => Some(*policy),
+            _ => None,
+        })
+    }
 
-        None
+    pub fn get_capacity(&self) -> std::option::Option<&syn::Expr> {
+        self.iter().find_map(|attr| match attr {
+            Ok(BuilderFieldAttribute::Capacity(capacity)) => Some(capacity),
+            _ => None,
+        })
     }
 
-    pub fn get_validator_paths(&self) -> std::vec::Vec<&syn::Path> {
+    pub fn get_separator(&self) -> std::option::Option<&syn::LitStr> {
+        self.iter().find_map(|attr| match attr {
+            Ok(BuilderFieldAttribute::Separator(separator)) => Some(separator),
+            _ => None,
+        })
+    }
+
+    pub fn is_group(&self) -> bool {
         self.iter()
-            .filter_map(|attr| {
-                if let Ok(attr) = attr {
-                    attr.get_validator_path()
-                } else {
-                    None
-                }
+            .any(|attr| matches!(attr, Ok(BuilderFieldAttribute::Group)))
+    }
+
+    pub fn is_sorted(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderFieldAttribute::Sorted)))
+    }
+
+    pub fn get_value_validations(&self) -> std::vec::Vec<&BuilderFieldAttribute> {
+        self.iter()
+            .flatten()
+            .filter(|attr| attr.is_value_validation())
+            .collect()
+    }
+
+    pub fn get_item_validator_paths(&self) -> std::vec::Vec<&syn::Path> {
+        self.iter()
+            .filter_map(|attr| match attr {
+                Ok(BuilderFieldAttribute::ValidateItem(path)) => Some(path),
+                _ => None,
             })
             .collect()
     }
 
+    pub fn is_display_errors(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderFieldAttribute::DisplayErrors)))
+    }
+
     pub fn get_default_path(&self) -> std::option::Option<&syn::Path> {
-        if let Some(BuilderFieldAttribute::Default(path)) = self.into_iter().flatten().next() {
-            return Some(path);
-        }
+        self.iter().find_map(|attr| match attr {
+            Ok(BuilderFieldAttribute::Default(path)) => Some(path),
+            _ => None,
+        })
+    }
+
+    pub fn is_cache_default(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderFieldAttribute::CacheDefault)))
+    }
 
-        None
+    pub fn is_clearable(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderFieldAttribute::Clearable)))
+    }
+
+    pub fn is_skip_from_str(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderFieldAttribute::SkipFromStr)))
+    }
+
+    pub fn is_tristate(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderFieldAttribute::Tristate)))
+    }
+
+    pub fn is_wrap(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderFieldAttribute::Wrap)))
+    }
+
+    pub fn is_treat_empty_as_missing(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderFieldAttribute::TreatEmptyAsMissing)))
+    }
+
+    pub fn is_required(&self) -> bool {
+        self.iter()
+            .any(|attr| matches!(attr, Ok(BuilderFieldAttribute::Required)))
+    }
+
+    pub fn get_code(&self) -> std::option::Option<&syn::LitStr> {
+        self.iter().find_map(|attr| match attr {
+            Ok(BuilderFieldAttribute::Code(code)) => Some(code),
+            _ => None,
+        })
+    }
+
+    pub fn get_missing_message(&self) -> std::option::Option<&syn::LitStr> {
+        self.iter().find_map(|attr| match attr {
+            Ok(BuilderFieldAttribute::MissingMessage(message)) => Some(message),
+            _ => None,
+        })
+    }
+
+    pub fn get_required_if_idents(&self) -> std::vec::Vec<&syn::Ident> {
+        self.iter()
+            .filter_map(|attr| match attr {
+                Ok(BuilderFieldAttribute::RequiredIf(ident)) => Some(ident),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn get_required_unless_idents(&self) -> std::vec::Vec<&syn::Ident> {
+        self.iter()
+            .filter_map(|attr| match attr {
+                Ok(BuilderFieldAttribute::RequiredUnless(ident)) => Some(ident),
+                _ => None,
+            })
+            .collect()
     }
 }
 
 impl From<syn::Attribute> for BuilderFieldAttributes {
     fn from(value: syn::Attribute) -> Self {
-        let mut attributes = vec![];
+        let attributes = parse_builder_items(&value, |meta, attributes| {
+            if meta.path.is_ident("each") {
+                let ident = parse_method_ident(meta.value()?)?;
 
-        if value.path().is_ident("builder") {
-            if let Err(err) = value.parse_nested_meta(|meta| {
-                if meta.path.is_ident("each") {
-                    let value = meta.value()?;
-                    let litstr: syn::LitStr = value.parse()?;
-                    let ident: syn::Ident = syn::parse_str(&litstr.value())?;
+                attributes.push(Ok(BuilderFieldAttribute::Each(ident)));
 
-                    attributes.push(Ok(BuilderFieldAttribute::Each(ident)));
+                return Ok(());
+            }
 
-                    return Ok(());
-                }
+            if meta.path.is_ident("insert_with") {
+                let ident = parse_method_ident(meta.value()?)?;
 
-                if meta.path.is_ident("validate") {
-                    let value = meta.value()?;
-                    let path: syn::Path = value.parse()?;
+                attributes.push(Ok(BuilderFieldAttribute::InsertWith(ident)));
 
-                    attributes.push(Ok(BuilderFieldAttribute::Validate(path)));
+                return Ok(());
+            }
 
-                    return Ok(());
-                }
+            if meta.path.is_ident("separator") {
+                let value = meta.value()?;
+                let separator: syn::LitStr = value.parse()?;
+
+                attributes.push(Ok(BuilderFieldAttribute::Separator(separator)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("group") {
+                attributes.push(Ok(BuilderFieldAttribute::Group));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("sorted") {
+                attributes.push(Ok(BuilderFieldAttribute::Sorted));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("capacity") {
+                let value = meta.value()?;
+                let capacity: syn::Expr = value.parse()?;
+
+                attributes.push(Ok(BuilderFieldAttribute::Capacity(capacity)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("dedup") {
+                let policy = if meta.input.peek(syn::Token![=]) {
+                    let litstr: syn::LitStr = meta.value()?.parse()?;
+
+                    match litstr.value().as_str() {
+                        "ignore" => DuplicatePolicy::Ignore,
+                        "error" => DuplicatePolicy::Error,
+                        _ => {
+                            return Err(syn::Error::new(
+                                litstr.span(),
+                                "expected one of `dedup = \"ignore\"` or `dedup = \"error\"`",
+                            ));
+                        }
+                    }
+                } else {
+                    DuplicatePolicy::Ignore
+                };
+
+                attributes.push(Ok(BuilderFieldAttribute::Dedup(policy)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("validate") {
+                let value = meta.value()?;
+                let path: syn::Path = value.parse()?;
+
+                attributes.push(Ok(BuilderFieldAttribute::Validate(path)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("check") {
+                let mut predicate = None;
+                let mut message = None;
+
+                meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("predicate") {
+                        predicate = Some(meta.value()?.parse()?);
+
+                        return Ok(());
+                    }
+
+                    if meta.path.is_ident("message") {
+                        message = Some(meta.value()?.parse()?);
+
+                        return Ok(());
+                    }
+
+                    Err(meta.error("expected `predicate = ...` or `message = \"...\"`"))
+                })?;
+
+                let (Some(predicate), Some(message)) = (predicate, message) else {
+                    return Err(meta.error("`check` requires both `predicate` and `message`"));
+                };
 
-                if meta.path.is_ident("default") {
-                    attributes.push(meta.value().map_or_else(
-                        |_| {
-                            Ok(BuilderFieldAttribute::Default(parse_quote!(
-                                std::default::Default::default
-                            )))
-                        },
-                        |value| {
-                            let path: syn::Path = value.parse()?;
-
-                            Ok(BuilderFieldAttribute::Default(path))
-                        },
-                    ));
-
-                    return Ok(());
+                attributes.push(Ok(BuilderFieldAttribute::Check { predicate, message }));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("range") {
+                let value = meta.value()?;
+                let range: syn::Expr = value.parse()?;
+
+                attributes.push(Ok(BuilderFieldAttribute::Range(range)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("len") {
+                let value = meta.value()?;
+                let range: syn::Expr = value.parse()?;
+
+                attributes.push(Ok(BuilderFieldAttribute::Len(range)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("non_empty") {
+                attributes.push(Ok(BuilderFieldAttribute::NonEmpty));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("matches") {
+                if !cfg!(feature = "regex") {
+                    return Err(meta.error("`matches` requires the `regex` feature"));
                 }
 
-                Err(meta.error("builder field attribute not recognized".to_string()))
-            }) {
-                attributes.push(Err(err));
+                let value = meta.value()?;
+                let pattern: syn::LitStr = value.parse()?;
+
+                attributes.push(Ok(BuilderFieldAttribute::Matches(pattern)));
+
+                return Ok(());
             }
-        }
+
+            if meta.path.is_ident("validate_with_name") {
+                let value = meta.value()?;
+                let path: syn::Path = value.parse()?;
+
+                attributes.push(Ok(BuilderFieldAttribute::ValidateWithName(path)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("display_errors") {
+                attributes.push(Ok(BuilderFieldAttribute::DisplayErrors));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("validate_item") {
+                let value = meta.value()?;
+                let path: syn::Path = value.parse()?;
+
+                attributes.push(Ok(BuilderFieldAttribute::ValidateItem(path)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("default") {
+                attributes.push(meta.value().map_or_else(
+                    |_| {
+                        Ok(BuilderFieldAttribute::Default(parse_quote_spanned!(
+                            meta.path.span()=> std::default::Default::default
+                        )))
+                    },
+                    |value| {
+                        let path: syn::Path = value.parse()?;
+
+                        Ok(BuilderFieldAttribute::Default(path))
+                    },
+                ));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("cache_default") {
+                attributes.push(Ok(BuilderFieldAttribute::CacheDefault));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("clearable") {
+                attributes.push(Ok(BuilderFieldAttribute::Clearable));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("skip_from_str") {
+                attributes.push(Ok(BuilderFieldAttribute::SkipFromStr));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("tristate") {
+                attributes.push(Ok(BuilderFieldAttribute::Tristate));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("wrap") {
+                attributes.push(Ok(BuilderFieldAttribute::Wrap));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("code") {
+                let value = meta.value()?;
+                let code: syn::LitStr = value.parse()?;
+
+                attributes.push(Ok(BuilderFieldAttribute::Code(code)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("missing_message") {
+                let value = meta.value()?;
+                let message: syn::LitStr = value.parse()?;
+
+                attributes.push(Ok(BuilderFieldAttribute::MissingMessage(message)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("required_if") {
+                let value = meta.value()?;
+                let field: syn::LitStr = value.parse()?;
+
+                attributes.push(Ok(BuilderFieldAttribute::RequiredIf(field.parse()?)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("required_unless") {
+                let value = meta.value()?;
+                let field: syn::LitStr = value.parse()?;
+
+                attributes.push(Ok(BuilderFieldAttribute::RequiredUnless(field.parse()?)));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("treat_empty_as_missing") {
+                attributes.push(Ok(BuilderFieldAttribute::TreatEmptyAsMissing));
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("required") {
+                attributes.push(Ok(BuilderFieldAttribute::Required));
+
+                return Ok(());
+            }
+
+            Err(meta.error("builder field attribute not recognized".to_string()))
+        });
 
         BuilderFieldAttributes(attributes)
     }
@@ -210,7 +1301,30 @@ impl<'a> IntoIterator for &'a BuilderFieldAttributes {
 
 impl FromIterator<syn::Result<BuilderFieldAttribute>> for BuilderFieldAttributes {
     fn from_iter<T: IntoIterator<Item = syn::Result<BuilderFieldAttribute>>>(iter: T) -> Self {
-        Self(iter.into_iter().collect())
+        let mut attributes: std::vec::Vec<_> = iter.into_iter().collect();
+
+        let mut seen = vec![];
+        let duplicates: std::vec::Vec<_> = attributes
+            .iter()
+            .flatten()
+            .filter_map(BuilderFieldAttribute::unique_name_and_span)
+            .filter_map(|(name, span)| {
+                if !seen.contains(&name) {
+                    seen.push(name);
+
+                    return None;
+                }
+
+                Some(Err(syn::Error::new(
+                    span,
+                    format!("duplicate `{name}` attribute"),
+                )))
+            })
+            .collect();
+
+        attributes.extend(duplicates);
+
+        Self(attributes)
     }
 }
 
@@ -218,30 +1332,199 @@ impl FromIterator<syn::Result<BuilderFieldAttribute>> for BuilderFieldAttributes
 mod tests {
     #![allow(unused_imports)]
     use super::*;
+    use syn::parse_quote;
 
     mod builder_struct_attributes {
         use super::*;
 
         #[test]
-        #[ignore = "not yet implemented"]
         fn _allow_for_multiple_validators() {
-            todo!()
+            let attributes: BuilderStructAttributes = [
+                parse_quote!(#[builder(validate = first, validate = second)]),
+                parse_quote!(#[builder(validate = third)]),
+            ]
+            .into_iter()
+            .flat_map(|attribute: syn::Attribute| BuilderStructAttributes::from(attribute))
+            .collect();
+
+            let first: syn::Path = parse_quote!(first);
+            let second: syn::Path = parse_quote!(second);
+            let third: syn::Path = parse_quote!(third);
+
+            assert_eq!(
+                attributes.get_validator_paths(),
+                vec![&first, &second, &third]
+            );
+        }
+
+        #[test]
+        fn _fill_in_unlisted_validation_stages() {
+            let attribute: syn::Attribute =
+                parse_quote!(#[builder(validate_order(pre, missing, struct))]);
+            let attributes = BuilderStructAttributes::from(attribute);
+
+            assert_eq!(
+                attributes.get_validate_order(),
+                vec![
+                    ValidationStage::Pre,
+                    ValidationStage::Missing,
+                    ValidationStage::Struct,
+                    ValidationStage::Fields,
+                    ValidationStage::Cross,
+                ]
+            );
+        }
+
+        #[test]
+        fn _require_missing_check_before_built_value_stages() {
+            let attribute: syn::Attribute =
+                parse_quote!(#[builder(validate_order(cross, missing))]);
+            let attributes = BuilderStructAttributes::from(attribute);
+
+            assert!(attributes.iter().any(Result::is_err));
+        }
+
+        #[test]
+        fn _recognise_validation_crate_integrations() {
+            let attribute: syn::Attribute = parse_quote!(#[builder(use_garde)]);
+            let attributes = BuilderStructAttributes::from(attribute);
+
+            assert!(attributes.is_use_garde());
+            assert!(!attributes.is_use_validator());
+        }
+
+        #[test]
+        fn _parse_build_mode() {
+            let attribute: syn::Attribute = parse_quote!(#[builder(build = "owned")]);
+            let attributes = BuilderStructAttributes::from(attribute);
+
+            assert_eq!(attributes.get_build_mode(), BuildMode::Owned);
+            assert_eq!(
+                BuilderStructAttributes::default().get_build_mode(),
+                BuildMode::Ref
+            );
+
+            let attribute: syn::Attribute = parse_quote!(#[builder(build = "take")]);
+            let attributes = BuilderStructAttributes::from(attribute);
+
+            assert_eq!(attributes.get_build_mode(), BuildMode::Take);
+
+            let attribute: syn::Attribute = parse_quote!(#[builder(build = "moved")]);
+            let attributes = BuilderStructAttributes::from(attribute);
+
+            assert!(attributes.iter().any(Result::is_err));
+        }
+
+        #[test]
+        fn _list_each_build_pointer_once() {
+            let attributes: BuilderStructAttributes = [
+                parse_quote!(#[builder(build_into(arc, boxed))]),
+                parse_quote!(#[builder(build_into(rc, arc))]),
+            ]
+            .into_iter()
+            .flat_map(|attribute: syn::Attribute| BuilderStructAttributes::from(attribute))
+            .collect();
+
+            assert_eq!(
+                attributes.get_build_pointers(),
+                vec![SmartPointer::Arc, SmartPointer::Box, SmartPointer::Rc]
+            );
         }
     }
 
     mod builder_field_attributes {
         use super::*;
 
+        fn collect(attributes: std::vec::Vec<syn::Attribute>) -> BuilderFieldAttributes {
+            attributes
+                .into_iter()
+                .flat_map(BuilderFieldAttributes::from)
+                .collect()
+        }
+
+        fn error_messages(attributes: &BuilderFieldAttributes) -> std::vec::Vec<String> {
+            attributes
+                .iter()
+                .filter_map(|attribute| attribute.as_ref().err())
+                .map(syn::Error::to_string)
+                .collect()
+        }
+
         #[test]
-        #[ignore = "not yet implemented"]
         fn _prevent_multiple_each_attributes() {
-            todo!()
+            let attributes = collect(vec![
+                parse_quote!(#[builder(each = "first")]),
+                parse_quote!(#[builder(each = "second")]),
+            ]);
+
+            let first: syn::Ident = parse_quote!(first);
+
+            assert_eq!(attributes.get_each_ident(), Some(&first));
+            assert_eq!(
+                error_messages(&attributes),
+                vec!["duplicate `each` attribute"]
+            );
         }
 
         #[test]
-        #[ignore = "not yet implemented"]
-        fn _require_field_with_each_attribute_to_be_container_type() {
-            todo!()
+        fn _accept_each_as_ident_or_string() {
+            let item: syn::Ident = parse_quote!(item);
+
+            for attribute in [
+                parse_quote!(#[builder(each = item)]),
+                parse_quote!(#[builder(each = "item")]),
+            ] {
+                let attributes = collect(vec![attribute]);
+
+                assert_eq!(attributes.get_each_ident(), Some(&item));
+                assert!(error_messages(&attributes).is_empty());
+            }
+        }
+
+        #[test]
+        fn _prevent_duplicates_within_one_attribute() {
+            let attributes = collect(vec![parse_quote!(
+                #[builder(default, code = "first", default = other, code = "second")]
+            )]);
+
+            assert_eq!(
+                error_messages(&attributes),
+                vec![
+                    "duplicate `default` attribute",
+                    "duplicate `code` attribute"
+                ]
+            );
+        }
+
+        #[test]
+        fn _find_attributes_which_are_not_listed_first() {
+            let attributes = collect(vec![parse_quote!(
+                #[builder(clearable, default = first, each = "item")]
+            )]);
+
+            let first: syn::Path = parse_quote!(first);
+            let item: syn::Ident = parse_quote!(item);
+
+            assert_eq!(attributes.get_default_path(), Some(&first));
+            assert_eq!(attributes.get_each_ident(), Some(&item));
+            assert!(error_messages(&attributes).is_empty());
+        }
+
+        #[test]
+        fn _keep_parsing_after_a_malformed_item() {
+            let attributes = collect(vec![parse_quote!(
+                #[builder(each = 1, unknown, code = "late", range = , clearable)]
+            )]);
+
+            assert_eq!(
+                error_messages(&attributes),
+                vec![
+                    "expected string literal or identifier",
+                    "builder field attribute not recognized",
+                    "expected an expression"
+                ]
+            );
+            assert!(attributes.is_clearable());
         }
     }
 }
@@ -1,49 +1,150 @@
 #![allow(dead_code)]
 
-#[derive(Default)]
-pub struct MissingFields(Option<Vec<&'static str>>);
+/// The name, type name, and custom message of each field of the target.
+type FieldTable = &'static [(&'static str, &'static str, Option<&'static str>)];
+
+/// Generated code keeps both tables in associated constants, sized by the
+/// number of fields and of similar pairs.
+pub struct MissingFields {
+    fields: FieldTable,
+    similar_pairs: &'static [(usize, usize)],
+    missing: [u64; 1],
+    similar: [u64; 1],
+}
 
 impl MissingFields {
-    fn add(&mut self, field: &'static str) -> &Self {
-        self.0.get_or_insert_default().push(field);
+    fn new(fields: FieldTable, similar_pairs: &'static [(usize, usize)]) -> Self {
+        Self {
+            fields,
+            similar_pairs,
+            missing: [0],
+            similar: [0],
+        }
+    }
+
+    fn add(&mut self, index: usize) -> &mut Self {
+        self.missing[index / 64] |= 1 << (index % 64);
         self
     }
 
-    fn add_if_none<T>(&mut self, field_name: &'static str, field: &Option<T>) -> &mut Self {
+    fn add_if_none<T>(&mut self, index: usize, field: &Option<T>) -> &mut Self {
         if field.is_none() {
-            self.add(field_name);
+            self.add(index);
         }
 
         self
     }
+
+    fn suggest(&mut self, pair: usize) -> &mut Self {
+        self.similar[pair / 64] |= 1 << (pair % 64);
+        self
+    }
+
+    fn is_missing(&self, index: usize) -> bool {
+        self.missing[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    fn is_suggested(&self, pair: usize) -> bool {
+        self.similar[pair / 64] & (1 << (pair % 64)) != 0
+    }
 }
 
 impl MissingFields {
     fn as_builder_error(&self) -> Result<(), BuilderError> {
-        let Some(missing_fields) = self.0.clone() else {
+        if self.missing.iter().all(|word| *word == 0) {
             return Ok(());
-        };
+        }
+
+        let missing = (0..self.fields.len())
+            .filter(|index| self.is_missing(*index))
+            .collect::<Vec<_>>();
+        let (with_message, without_message): (Vec<usize>, Vec<usize>) = missing
+            .iter()
+            .partition(|index| self.fields[**index].2.is_some());
 
-        Err(BuilderError::missing_fields(&missing_fields))
+        let missing_fields = (!without_message.is_empty()).then(|| {
+            let described = without_message
+                .iter()
+                .map(|index| {
+                    let (field, type_name, _) = self.fields[*index];
+
+                    format!("{field}: {type_name}")
+                })
+                .collect::<Vec<_>>();
+            let described = described.iter().map(String::as_str).collect::<Vec<_>>();
+
+            let hints = self
+                .similar_pairs
+                .iter()
+                .enumerate()
+                .filter(|(pair, (field, _))| {
+                    self.is_suggested(*pair) && without_message.contains(field)
+                })
+                .map(|(_, (field, set_field))| {
+                    format!(
+                        "`{}` was set, did you mean `{}`?",
+                        self.fields[*set_field].0, self.fields[*field].0,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            let mut error = BuilderError::missing_fields(&described);
+            if let BuilderError::InvalidState { message, .. } = &mut error
+                && !hints.is_empty()
+            {
+                *message = format!("{message} ({})", hints.join(" ")).into();
+            }
+
+            error
+        });
+        let missing_with_message = with_message.into_iter().map(|index| {
+            let (field, _, message) = self.fields[index];
+
+            BuilderError::missing_field_with_message(field, message.unwrap_or_default())
+        });
+
+        Err(BuilderError::multiple(
+            missing_fields.into_iter().chain(missing_with_message),
+        ))
     }
 }
 
 /// Occurs when the user either tries to incorrectly assign a field,
 /// or when they attempt to build the target struct while the builder
 /// is in an invalid state.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub enum BuilderError {
     /// Typically occurs on the `build()` method. Examples include:
     /// missing fields, constraint violations, and illogical structs.
     InvalidState {
         message: std::borrow::Cow<'static, str>,
+        /// A stable identifier for the failure, see `error_code()`.
+        code: Option<&'static str>,
     },
     /// Typically occurs on the setter functions. Allows the builder
     /// to catch problems before the user attempts to build the target.
     InvalidField {
         field_name: std::borrow::Cow<'static, str>,
         message: std::borrow::Cow<'static, str>,
+        /// Where the failing setter was called, when known.
+        location: Option<&'static std::panic::Location<'static>>,
+        /// A stable identifier for the failure, see `error_code()`.
+        code: Option<&'static str>,
+        /// The underlying error which caused the failure, when known.
+        source: Option<std::sync::Arc<dyn std::error::Error + Send + Sync>>,
     },
+    /// Occurs when the built value fails a struct-level validation,
+    /// such as a cross-field check, rather than missing a field.
+    ValidationFailed {
+        message: std::borrow::Cow<'static, str>,
+        /// The fields involved in the failure, when known.
+        fields: Vec<std::borrow::Cow<'static, str>>,
+        /// A stable identifier for the failure, see `error_code()`.
+        code: Option<&'static str>,
+    },
+    /// Groups several errors which were collected before being
+    /// reported together.
+    Multiple { errors: Vec<BuilderError> },
 }
 
 impl BuilderError {
@@ -55,29 +156,324 @@ impl BuilderError {
             .unwrap_or_default();
         Self::InvalidState {
             message: format!("missing required field(s): {missing_field_names}").into(),
+            code: Some("missing_fields"),
         }
     }
 
     pub fn missing_field(field: &str) -> Self {
         Self::missing_fields(&[field])
     }
+
+    pub fn missing_field_with_message(
+        field: impl Into<std::borrow::Cow<'static, str>>,
+        message: impl Into<std::borrow::Cow<'static, str>>,
+    ) -> Self {
+        Self::InvalidField {
+            field_name: field.into(),
+            message: message.into(),
+            location: None,
+            code: Some("missing_fields"),
+            source: None,
+        }
+    }
+
+    pub fn invalid_state(message: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self::InvalidState {
+            message: message.into(),
+            code: None,
+        }
+    }
+
+    pub fn invalid_fields(fields: &[&str], message: impl std::fmt::Display) -> Self {
+        let field_names = fields
+            .iter()
+            .map(|field_name| format!("`{field_name}`"))
+            .reduce(|acc, next| format!("{acc}, {next}"))
+            .unwrap_or_default();
+        Self::ValidationFailed {
+            message: format!("invalid field(s) {field_names}: {message}").into(),
+            fields: fields
+                .iter()
+                .map(|field| field.to_string().into())
+                .collect(),
+            code: Some("invalid_fields"),
+        }
+    }
+
+    pub fn validation_failed(message: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self::ValidationFailed {
+            message: message.into(),
+            fields: Vec::new(),
+            code: None,
+        }
+    }
+
+    pub fn with_fields<F>(mut self, involved: impl IntoIterator<Item = F>) -> Self
+    where
+        F: Into<std::borrow::Cow<'static, str>>,
+    {
+        if let Self::ValidationFailed { fields, .. } = &mut self {
+            fields.extend(involved.into_iter().map(Into::into));
+        }
+
+        self
+    }
+
+    pub fn invalid_field(
+        field_name: impl Into<std::borrow::Cow<'static, str>>,
+        message: impl Into<std::borrow::Cow<'static, str>>,
+    ) -> Self {
+        Self::InvalidField {
+            field_name: field_name.into(),
+            message: message.into(),
+            location: None,
+            code: None,
+            source: None,
+        }
+    }
+
+    pub fn multiple(errors: impl IntoIterator<Item = Self>) -> Self {
+        let mut errors: Vec<Self> = errors
+            .into_iter()
+            .flat_map(|error| match error {
+                Self::Multiple { errors } => errors,
+                error => vec![error],
+            })
+            .collect();
+
+        if errors.len() == 1 {
+            return errors.remove(0);
+        }
+
+        Self::Multiple { errors }
+    }
+
+    pub fn for_item(mut self, field: &str, index: usize) -> Self {
+        if let Self::InvalidField { field_name, .. } = &mut self {
+            *field_name = format!("{field}[{index}]").into();
+        }
+
+        self
+    }
+
+    pub fn with_source(mut self, err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        if let Self::InvalidField { source, .. } = &mut self {
+            *source = Some(std::sync::Arc::new(err));
+        }
+
+        self
+    }
+
+    pub fn with_code(mut self, error_code: &'static str) -> Self {
+        if let Self::InvalidState { code, .. }
+        | Self::InvalidField { code, .. }
+        | Self::ValidationFailed { code, .. } = &mut self
+        {
+            *code = Some(error_code);
+        }
+
+        self
+    }
+
+    /// A stable, machine-readable identifier for the failure, so
+    /// callers can map errors without matching on their messages.
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            Self::InvalidState { code, .. } => code.unwrap_or("invalid_state"),
+            Self::InvalidField { code, .. } => code.unwrap_or("invalid_field"),
+            Self::ValidationFailed { code, .. } => code.unwrap_or("validation_failed"),
+            Self::Multiple { .. } => "multiple",
+        }
+    }
+
+    pub fn located_at(mut self, caller: &'static std::panic::Location<'static>) -> Self {
+        if let Self::InvalidField { location, .. } = &mut self {
+            *location = Some(caller);
+        }
+
+        self
+    }
+
+    /// Flattens the error into one entry per failure, in a shape meant to be
+    /// handed to clients, such as the body of an HTTP response.
+    pub fn to_report(&self) -> BuilderErrorReport {
+        let mut errors = vec![];
+        self.collect_report_entries(&mut errors);
+
+        BuilderErrorReport { errors }
+    }
+
+    fn collect_report_entries(&self, entries: &mut Vec<BuilderErrorReportEntry>) {
+        let entry =
+            |field, kind, message: &std::borrow::Cow<'static, str>| BuilderErrorReportEntry {
+                field,
+                kind,
+                code: self.error_code(),
+                message: message.clone(),
+            };
+
+        match self {
+            Self::InvalidState { message, .. } => {
+                entries.push(entry(None, "invalid_state", message));
+            }
+            Self::InvalidField {
+                field_name,
+                message,
+                ..
+            } => {
+                entries.push(entry(Some(field_name.clone()), "invalid_field", message));
+            }
+            Self::ValidationFailed {
+                message, fields, ..
+            } if fields.is_empty() => {
+                entries.push(entry(None, "validation_failed", message));
+            }
+            // each involved field gets the message, so clients can show it next to all of them
+            Self::ValidationFailed {
+                message, fields, ..
+            } => {
+                entries.extend(
+                    fields
+                        .iter()
+                        .map(|field| entry(Some(field.clone()), "validation_failed", message)),
+                );
+            }
+            Self::Multiple { errors } => {
+                errors
+                    .iter()
+                    .for_each(|error| error.collect_report_entries(entries));
+            }
+        }
+    }
+}
+
+/// A flat summary of a `BuilderError`, see `BuilderError::to_report()`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BuilderErrorReport {
+    pub errors: Vec<BuilderErrorReportEntry>,
+}
+
+/// A single failure within a `BuilderErrorReport`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BuilderErrorReportEntry {
+    /// The field which failed, or `None` when the failure is not tied to one.
+    pub field: Option<std::borrow::Cow<'static, str>>,
+    /// One of `invalid_state`, `invalid_field`, or `validation_failed`.
+    pub kind: &'static str,
+    /// The error's `error_code()`.
+    pub code: &'static str,
+    pub message: std::borrow::Cow<'static, str>,
 }
 
 impl std::fmt::Display for BuilderError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            BuilderError::InvalidState { message } => {
+            BuilderError::InvalidState { message, .. } => {
                 write!(f, "Unable to build #Target: {message}")
             }
             BuilderError::InvalidField {
                 field_name,
                 message,
-            } => write!(f, "Unable to assign field `{field_name}`: {message}"),
+                location,
+                ..
+            } => {
+                write!(f, "Unable to assign field `{field_name}`: {message}")?;
+
+                // `{:#}` points at the setter call which failed
+                match location {
+                    Some(location) if f.alternate() => write!(f, " (at {location})"),
+                    _ => Ok(()),
+                }
+            }
+            BuilderError::ValidationFailed { message, .. } => {
+                write!(f, "Unable to build #Target: {message}")
+            }
+            BuilderError::Multiple { errors } => {
+                write!(f, "Unable to build #Target: {} errors", errors.len())?;
+
+                // one line for logs, or a report with an error per line
+                if f.alternate() {
+                    for error in errors {
+                        write!(f, "\n  - {error:#}")?;
+                    }
+
+                    return Ok(());
+                }
+
+                let errors = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+                write!(f, " ({})", errors.join("; "))
+            }
         }
     }
 }
 
-impl std::error::Error for BuilderError {}
+// the source is the cause of an error, not part of its identity
+impl PartialEq for BuilderError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::InvalidState { message, code },
+                Self::InvalidState {
+                    message: other_message,
+                    code: other_code,
+                },
+            ) => message == other_message && code == other_code,
+            (
+                Self::InvalidField {
+                    field_name,
+                    message,
+                    location,
+                    code,
+                    ..
+                },
+                Self::InvalidField {
+                    field_name: other_field_name,
+                    message: other_message,
+                    location: other_location,
+                    code: other_code,
+                    ..
+                },
+            ) => {
+                field_name == other_field_name
+                    && message == other_message
+                    && location == other_location
+                    && code == other_code
+            }
+            (
+                Self::ValidationFailed {
+                    message,
+                    fields,
+                    code,
+                },
+                Self::ValidationFailed {
+                    message: other_message,
+                    fields: other_fields,
+                    code: other_code,
+                },
+            ) => message == other_message && fields == other_fields && code == other_code,
+            (
+                Self::Multiple { errors },
+                Self::Multiple {
+                    errors: other_errors,
+                },
+            ) => errors == other_errors,
+            _ => false,
+        }
+    }
+}
+
+impl std::error::Error for BuilderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BuilderError::InvalidField {
+                source: Some(source),
+                ..
+            } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -85,50 +481,106 @@ mod tests {
 
     #[test]
     fn _can_add_missing_fields() {
-        let mut missing_fields = MissingFields::default();
+        let mut missing_fields = MissingFields::new(&[("first", "u8", None)], &[]);
 
-        assert!(missing_fields.0.is_none());
+        assert!(!missing_fields.is_missing(0));
 
-        missing_fields.add("first");
+        missing_fields.add(0);
 
-        assert!(
-            missing_fields
-                .0
-                .is_some_and(|fields| fields.contains(&"first"))
-        );
+        assert!(missing_fields.is_missing(0));
     }
 
     #[test]
     fn _should_add_if_missing() {
-        let mut missing_fields = MissingFields::default();
+        let mut missing_fields =
+            MissingFields::new(&[("first", "&str", None), ("first again", "()", None)], &[]);
 
-        missing_fields.add_if_none("first", &Some("value"));
+        missing_fields.add_if_none(0, &Some("value"));
 
-        assert!(missing_fields.0.is_none());
+        assert!(!missing_fields.is_missing(0));
 
-        missing_fields.add_if_none::<()>("first again", &None);
+        missing_fields.add_if_none::<()>(1, &None);
 
-        assert!(
-            missing_fields
-                .0
-                .is_some_and(|fields| fields.contains(&"first again"))
-        )
+        assert!(missing_fields.is_missing(1));
     }
 
     #[test]
     fn _can_convert_missing_fields_to_error() {
-        let mut missing_fields = MissingFields::default();
+        let mut missing_fields =
+            MissingFields::new(&[("first", "u16", None), ("second", "String", None)], &[]);
 
         missing_fields
-            .add_if_none::<()>("first", &None)
-            .add_if_none::<()>("second", &None);
+            .add_if_none::<()>(0, &None)
+            .add_if_none::<()>(1, &None);
+
+        let result = missing_fields.as_builder_error();
+
+        assert_eq!(
+            result,
+            Err(BuilderError::InvalidState {
+                message: "missing required field(s): `first: u16`, `second: String`".into(),
+                code: Some("missing_fields"),
+            })
+        )
+    }
+
+    #[test]
+    fn _suggests_similar_fields_which_were_set() {
+        let mut missing_fields = MissingFields::new(
+            &[
+                ("port", "u16", None),
+                ("ports", "u16", None),
+                ("host", "String", None),
+                ("hosts", "String", None),
+            ],
+            &[(0, 1), (2, 3)],
+        );
+
+        missing_fields.add(0);
+        missing_fields.suggest(0);
+        missing_fields.suggest(1);
 
         let result = missing_fields.as_builder_error();
 
         assert_eq!(
             result,
             Err(BuilderError::InvalidState {
-                message: "missing required field(s): `first`, `second`".into()
+                message: "missing required field(s): `port: u16` \
+                    (`ports` was set, did you mean `port`?)"
+                    .into(),
+                code: Some("missing_fields"),
+            })
+        )
+    }
+
+    #[test]
+    fn _can_convert_missing_fields_with_messages_to_error() {
+        let mut missing_fields = MissingFields::new(
+            &[
+                ("first", "bool", None),
+                ("api_key", "String", Some("an API key is required")),
+            ],
+            &[],
+        );
+
+        missing_fields.add(0);
+        missing_fields.add(1);
+
+        let result = missing_fields.as_builder_error();
+
+        assert_eq!(
+            result,
+            Err(BuilderError::Multiple {
+                errors: vec![
+                    BuilderError::missing_field("first: bool"),
+                    BuilderError::InvalidField {
+                        field_name: "api_key".into(),
+                        message: "an API key is required".into(),
+                        location: None,
+                        code: Some("missing_fields"),
+                        source: None,
+                    },
+                ],
             })
         )
     }
@@ -140,8 +592,234 @@ mod tests {
         assert_eq!(
             result,
             BuilderError::InvalidState {
-                message: "missing required field(s): `one`, `two`, `three`".into()
+                message: "missing required field(s): `one`, `two`, `three`".into(),
+                code: Some("missing_fields"),
             }
         )
     }
+
+    #[test]
+    fn _invalid_field_accepts_owned_and_borrowed_strings() {
+        let result = BuilderError::invalid_field("port".to_string(), "must not be zero");
+
+        assert_eq!(
+            result,
+            BuilderError::InvalidField {
+                field_name: "port".into(),
+                message: "must not be zero".into(),
+                location: None,
+                code: None,
+                source: None,
+            }
+        )
+    }
+
+    #[test]
+    fn _located_at_only_applies_to_invalid_field() {
+        let location = std::panic::Location::caller();
+
+        let result = BuilderError::invalid_field("port", "must not be zero").located_at(location);
+
+        assert!(matches!(
+            result,
+            BuilderError::InvalidField {
+                location: Some(l),
+                ..
+            } if l == location
+        ));
+
+        let result = BuilderError::missing_field("port").located_at(location);
+
+        assert_eq!(result, BuilderError::missing_field("port"));
+    }
+
+    #[test]
+    fn _alternate_display_includes_the_location() {
+        let location = std::panic::Location::caller();
+        let error = BuilderError::invalid_field("port", "bad").located_at(location);
+
+        assert_eq!(error.to_string(), "Unable to assign field `port`: bad");
+        assert_eq!(
+            format!("{error:#}"),
+            format!("Unable to assign field `port`: bad (at {location})")
+        );
+
+        let errors = BuilderError::multiple([error, BuilderError::invalid_field("host", "bad")]);
+
+        assert_eq!(
+            errors.to_string(),
+            "Unable to build #Target: 2 errors \
+            (Unable to assign field `port`: bad; Unable to assign field `host`: bad)"
+        );
+        assert_eq!(
+            format!("{errors:#}"),
+            format!(
+                "Unable to build #Target: 2 errors\
+                \n  - Unable to assign field `port`: bad (at {location})\
+                \n  - Unable to assign field `host`: bad"
+            )
+        );
+    }
+
+    #[test]
+    fn _for_item_names_the_offending_element() {
+        let result = BuilderError::invalid_field("tag", "cannot be empty").for_item("tags", 2);
+
+        assert_eq!(
+            result,
+            BuilderError::invalid_field("tags[2]", "cannot be empty")
+        );
+    }
+
+    #[test]
+    fn _invalid_fields_names_every_field() {
+        let result = BuilderError::invalid_fields(&["start", "end"], "start must not exceed end");
+
+        assert_eq!(
+            result,
+            BuilderError::ValidationFailed {
+                message: "invalid field(s) `start`, `end`: start must not exceed end".into(),
+                fields: vec!["start".into(), "end".into()],
+                code: Some("invalid_fields"),
+            }
+        )
+    }
+
+    #[test]
+    fn _validation_failed_collects_the_involved_fields() {
+        let result = BuilderError::validation_failed("too many replicas")
+            .with_fields(["replicas"])
+            .with_fields([String::from("zones")]);
+
+        assert_eq!(
+            result,
+            BuilderError::ValidationFailed {
+                message: "too many replicas".into(),
+                fields: vec!["replicas".into(), "zones".into()],
+                code: None,
+            }
+        );
+        assert_eq!(result.error_code(), "validation_failed");
+        assert_ne!(result, BuilderError::invalid_state("too many replicas"));
+    }
+
+    #[test]
+    fn _invalid_state_accepts_owned_and_borrowed_strings() {
+        assert_eq!(
+            BuilderError::invalid_state("static message"),
+            BuilderError::invalid_state(String::from("static message"))
+        );
+    }
+
+    #[test]
+    fn _multiple_flattens_and_unwraps_single_errors() {
+        let single = BuilderError::multiple([BuilderError::invalid_state("only")]);
+
+        assert_eq!(single, BuilderError::invalid_state("only"));
+
+        let nested = BuilderError::multiple([
+            BuilderError::invalid_field("a", "bad"),
+            BuilderError::multiple([
+                BuilderError::invalid_field("b", "bad"),
+                BuilderError::invalid_field("c", "bad"),
+            ]),
+        ]);
+
+        assert_eq!(
+            nested,
+            BuilderError::Multiple {
+                errors: vec![
+                    BuilderError::invalid_field("a", "bad"),
+                    BuilderError::invalid_field("b", "bad"),
+                    BuilderError::invalid_field("c", "bad"),
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn _error_code_defaults_per_kind_and_can_be_overridden() {
+        assert_eq!(
+            BuilderError::missing_field("port").error_code(),
+            "missing_fields"
+        );
+        assert_eq!(
+            BuilderError::invalid_state("bad").error_code(),
+            "invalid_state"
+        );
+        assert_eq!(
+            BuilderError::invalid_field("port", "bad").error_code(),
+            "invalid_field"
+        );
+        assert_eq!(
+            BuilderError::invalid_field("port", "bad")
+                .with_code("port_out_of_range")
+                .error_code(),
+            "port_out_of_range"
+        );
+    }
+
+    #[test]
+    fn _with_source_exposes_the_underlying_error() {
+        use std::error::Error;
+
+        let cause = "x".parse::<u16>().unwrap_err();
+        let result = BuilderError::invalid_field("port", "not a number").with_source(cause.clone());
+
+        assert_eq!(
+            result.source().map(ToString::to_string),
+            Some(cause.to_string())
+        );
+        assert_eq!(result, BuilderError::invalid_field("port", "not a number"));
+    }
+
+    #[test]
+    fn _report_has_one_entry_per_failure() {
+        let error = BuilderError::multiple([
+            BuilderError::missing_field("host"),
+            BuilderError::invalid_field("port", "out of range").with_code("port_out_of_range"),
+            BuilderError::invalid_fields(&["min", "max"], "min exceeds max"),
+        ]);
+
+        let entry = |field: Option<&'static str>, kind, code, message: &'static str| {
+            BuilderErrorReportEntry {
+                field: field.map(Into::into),
+                kind,
+                code,
+                message: message.into(),
+            }
+        };
+
+        assert_eq!(
+            error.to_report(),
+            BuilderErrorReport {
+                errors: vec![
+                    entry(
+                        None,
+                        "invalid_state",
+                        "missing_fields",
+                        "missing required field(s): `host`"
+                    ),
+                    entry(
+                        Some("port"),
+                        "invalid_field",
+                        "port_out_of_range",
+                        "out of range"
+                    ),
+                    entry(
+                        Some("min"),
+                        "validation_failed",
+                        "invalid_fields",
+                        "invalid field(s) `min`, `max`: min exceeds max"
+                    ),
+                    entry(
+                        Some("max"),
+                        "validation_failed",
+                        "invalid_fields",
+                        "invalid field(s) `min`, `max`: min exceeds max"
+                    ),
+                ]
+            }
+        );
+    }
 }
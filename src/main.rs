@@ -5,7 +5,7 @@ use turann::Builder;
 #[derive(Debug, Builder)]
 #[builder(validate = Self::multi_has_items)]
 pub struct Target {
-    #[builder(validate = Self::required_not_empty)]
+    #[builder(non_empty)]
     pub required: String,
     pub optional_unset: Option<String>,
     pub optional_set: Option<String>,
@@ -24,21 +24,11 @@ impl TargetBuilder {
         42
     }
 
-    fn required_not_empty(value: String) -> Result<String, TargetBuilderError> {
-        if value.is_empty() {
-            return Err(TargetBuilderError::InvalidField {
-                field_name: "required".into(),
-                message: "cannot be empty".into(),
-            });
-        }
-        Ok(value)
-    }
-
     fn multi_has_items(target: Target) -> Result<Target, TargetBuilderError> {
         if target.vec_multi.is_empty() {
-            return Err(TargetBuilderError::InvalidState {
-                message: "vec_multi cannot be empty".into(),
-            });
+            return Err(TargetBuilderError::invalid_state(
+                "vec_multi cannot be empty",
+            ));
         }
 
         Ok(target)
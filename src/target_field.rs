@@ -1,7 +1,10 @@
-use quote::quote;
+use quote::{format_ident, quote, quote_spanned};
 use syn::{self, PathArguments, spanned::Spanned};
 
-use crate::builder_attribute::BuilderFieldAttributes;
+use crate::builder_attribute::{
+    BuildMode, BuilderFieldAttribute, BuilderFieldAttributes, BuilderStructAttributes,
+    DuplicatePolicy,
+};
 
 fn is_container(ident: &'static str, ty: &syn::Type) -> bool {
     let syn::Type::Path(p) = ty else {
@@ -19,6 +22,247 @@ fn is_container(ident: &'static str, ty: &syn::Type) -> bool {
     segment.ident == ident && matches!(segment.arguments, PathArguments::AngleBracketed(_))
 }
 
+/// A collection which an `each` method adds one item to at a time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EachCollection {
+    Vec,
+    VecDeque,
+    Set,
+    Map,
+    /// A `smallvec::SmallVec<[T; N]>`, with the `smallvec` feature.
+    SmallVec,
+    /// An `arrayvec::ArrayVec<T, N>`, with the `arrayvec` feature, which
+    /// rejects items once it is full.
+    ArrayVec,
+    /// A `String`, which every item is appended to.
+    String,
+    /// A collection added to with the method named by `insert_with`.
+    Custom,
+}
+
+fn each_collection(ty: &syn::Type) -> std::option::Option<EachCollection> {
+    if is_string(ty) {
+        return Some(EachCollection::String);
+    }
+
+    let syn::Type::Path(p) = ty else {
+        return std::option::Option::None;
+    };
+
+    let segment = p.path.segments.last()?;
+
+    if !matches!(segment.arguments, PathArguments::AngleBracketed(_)) {
+        return std::option::Option::None;
+    }
+
+    match segment.ident.to_string().as_str() {
+        "Vec" => Some(EachCollection::Vec),
+        "VecDeque" => Some(EachCollection::VecDeque),
+        // the item type is taken from the only generic argument, which rules
+        // out sets with a custom hasher
+        "HashSet" | "BTreeSet" if inner_type(ty).is_some() => Some(EachCollection::Set),
+        "HashMap" | "BTreeMap" => Some(EachCollection::Map),
+        "SmallVec" if cfg!(feature = "smallvec") && inner_type(ty).is_some() => {
+            Some(EachCollection::SmallVec)
+        }
+        "ArrayVec" if cfg!(feature = "arrayvec") && !type_arguments(ty).is_empty() => {
+            Some(EachCollection::ArrayVec)
+        }
+        _ => None,
+    }
+}
+
+/// The type of the items of a collection.
+fn item_type(collection: EachCollection, ty: &syn::Type) -> std::option::Option<&syn::Type> {
+    match collection {
+        // the only argument is the inline array, `[T; N]`
+        EachCollection::SmallVec => match inner_type(ty)? {
+            syn::Type::Array(array) => Some(&array.elem),
+            _ => None,
+        },
+        EachCollection::ArrayVec => type_arguments(ty).first().copied(),
+        EachCollection::String => Some(ty),
+        _ => inner_type(ty),
+    }
+}
+
+/// The type arguments of a type, skipping lifetimes and consts.
+fn type_arguments(ty: &syn::Type) -> std::vec::Vec<&syn::Type> {
+    let syn::Type::Path(p) = ty else {
+        return vec![];
+    };
+
+    let Some(PathArguments::AngleBracketed(generics)) =
+        p.path.segments.last().map(|segment| &segment.arguments)
+    else {
+        return vec![];
+    };
+
+    generics
+        .args
+        .iter()
+        .filter_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The key and value types of a map.
+fn map_types(ty: &syn::Type) -> std::option::Option<(&syn::Type, &syn::Type)> {
+    match type_arguments(ty)[..] {
+        [key_type, value_type, ..] => Some((key_type, value_type)),
+        _ => None,
+    }
+}
+
+fn is_static_str_cow(ty: &syn::Type) -> bool {
+    let syn::Type::Path(p) = ty else {
+        return false;
+    };
+
+    if p.qself.is_some() {
+        return false;
+    }
+
+    let Some(segment) = p.path.segments.last() else {
+        return false;
+    };
+
+    let PathArguments::AngleBracketed(generics) = &segment.arguments else {
+        return false;
+    };
+
+    let [
+        syn::GenericArgument::Lifetime(lifetime),
+        syn::GenericArgument::Type(syn::Type::Path(inner)),
+    ] = generics.args.iter().collect::<Vec<_>>()[..]
+    else {
+        return false;
+    };
+
+    segment.ident == "Cow" && lifetime.ident == "static" && inner.path.is_ident("str")
+}
+
+fn smart_pointer_ident(ty: &syn::Type) -> std::option::Option<&syn::Ident> {
+    let syn::Type::Path(p) = ty else {
+        return std::option::Option::None;
+    };
+
+    let segment = p.path.segments.last()?;
+
+    inner_type(ty)?;
+
+    ["Arc", "Rc", "Box"]
+        .iter()
+        .any(|pointer| segment.ident == pointer)
+        .then_some(&segment.ident)
+}
+
+/// Whether the type is `std::time::Duration`, spelled out or imported,
+/// which rules out the `Duration`s of other crates, like `chrono`'s.
+fn is_duration(ty: &syn::Type) -> bool {
+    let syn::Type::Path(p) = ty else {
+        return false;
+    };
+
+    if p.qself.is_some()
+        || p.path
+            .segments
+            .iter()
+            .any(|segment| !segment.arguments.is_none())
+    {
+        return false;
+    }
+
+    let segments = p
+        .path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<std::vec::Vec<_>>();
+    let segments = segments
+        .iter()
+        .map(String::as_str)
+        .collect::<std::vec::Vec<_>>();
+
+    matches!(
+        segments[..],
+        ["Duration"] | ["std" | "core", "time", "Duration"]
+    )
+}
+
+fn is_chrono_date_time(ty: &syn::Type) -> bool {
+    is_container("DateTime", ty) && inner_type(ty).is_some()
+}
+
+fn is_time_offset_date_time(ty: &syn::Type) -> bool {
+    let syn::Type::Path(p) = ty else {
+        return false;
+    };
+
+    p.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "OffsetDateTime" && segment.arguments.is_none())
+}
+
+fn is_string(ty: &syn::Type) -> bool {
+    let syn::Type::Path(p) = ty else {
+        return false;
+    };
+
+    p.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "String" && segment.arguments.is_none())
+}
+
+fn len_message(range: &syn::Expr) -> String {
+    format!("length must be in range {}", range_text(range))
+}
+
+fn range_text(range: &syn::Expr) -> String {
+    let syn::Expr::Range(range) = range else {
+        return compact_text(quote! { #range });
+    };
+
+    let limits = match range.limits {
+        syn::RangeLimits::HalfOpen(_) => "..",
+        syn::RangeLimits::Closed(_) => "..=",
+    };
+    let bound = |bound: &std::option::Option<std::boxed::Box<syn::Expr>>| {
+        bound
+            .as_ref()
+            .map(|bound| compact_text(quote! { #bound }))
+            .unwrap_or_default()
+    };
+
+    format!("{}{limits}{}", bound(&range.start), bound(&range.end))
+}
+
+fn type_name(ty: &syn::Type) -> String {
+    compact_text(quote! { #ty })
+}
+
+fn compact_text(tokens: proc_macro2::TokenStream) -> String {
+    let tokens = tokens.to_string();
+    let chars = tokens.chars().collect::<std::vec::Vec<_>>();
+    let is_word = |c: &char| c.is_alphanumeric() || *c == '_';
+
+    // keep only the spaces which separate two words, or follow a comma
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| {
+            **c != ' '
+                || chars[i - 1] == ','
+                || (is_word(&chars[i - 1]) && chars.get(i + 1).is_some_and(is_word))
+        })
+        .map(|(_, c)| c)
+        .collect()
+}
+
 fn inner_type(outer_type: &syn::Type) -> std::option::Option<&syn::Type> {
     let syn::Type::Path(outer_type) = outer_type else {
         return std::option::Option::None;
@@ -56,6 +300,12 @@ pub struct TargetField {
     pub ident: syn::Ident,
     pub ty: syn::Type,
     pub attributes: BuilderFieldAttributes,
+    pub struct_attributes: BuilderStructAttributes,
+    /// The enum storing `tristate` fields, generated next to the builder.
+    pub tristate_ident: syn::Ident,
+    /// The position of the field in the struct, and of its bit in the
+    /// missing fields set.
+    pub index: usize,
 }
 
 impl TargetField {
@@ -63,6 +313,10 @@ impl TargetField {
         is_container("Option", &self.ty)
     }
 
+    pub fn is_tristate(&self) -> bool {
+        self.attributes.is_tristate() && self.is_optional()
+    }
+
     fn is_vec(&self) -> bool {
         is_container("Vec", &self.ty)
     }
@@ -70,161 +324,2584 @@ impl TargetField {
     fn has_each_method(&self) -> bool {
         self.attributes.get_each_ident().is_some()
 
-        // FIXME: move `Vec` validation to `BuilderAttributes`
-        && self.is_vec()
-    }
-
-    fn has_default(&self) -> bool {
-        self.attributes.get_default_path().is_some()
+        // FIXME: move collection validation to `BuilderAttributes`
+        && self.each_collection().is_some()
     }
 
-    pub fn quote_attr_errors(&self) -> proc_macro2::TokenStream {
-        let errors = self.attributes.0.iter().filter_map(|a| match a {
-            Ok(_) => std::option::Option::None,
-            Err(e) => e.to_compile_error().into(),
-        });
+    /// The collection an `each` method adds to, any collection with a single
+    /// item type if the method to add with is given by `insert_with`.
+    fn each_collection(&self) -> std::option::Option<EachCollection> {
+        let collection_type = self.collection_type()?;
 
-        quote! {
-            #(#errors)*
+        if self.attributes.get_insert_with_ident().is_some() {
+            return inner_type(collection_type).map(|_| EachCollection::Custom);
         }
-    }
 
-    pub fn quote_builder_field(&self) -> proc_macro2::TokenStream {
-        let ident = &self.ident;
-        let ty = &self.ty;
+        each_collection(collection_type)
+    }
 
-        if self.is_optional() || self.has_each_method() {
-            return quote! { pub #ident: #ty, };
+    /// The type of the collection an `each` method adds to, which an optional
+    /// field wraps.
+    fn collection_type(&self) -> std::option::Option<&syn::Type> {
+        if self.is_optional() {
+            return inner_type(&self.ty);
         }
 
-        quote! { pub #ident: std::option::Option<#ty>, }
+        Some(&self.ty)
     }
 
-    pub fn quote_setter(&self, builder_error_ident: &syn::Ident) -> proc_macro2::TokenStream {
+    /// Whether the builder holds the collection of an `each` method itself,
+    /// rather than an `Option` which the first item fills in. A `required`
+    /// collection is held in an `Option` too, to tell whether it was set, and
+    /// so is one with a `default`, which the first item is added to.
+    fn holds_collection(&self) -> bool {
+        self.has_each_method()
+            && !self.is_optional()
+            && !self.attributes.is_required()
+            && !self.has_default()
+    }
+
+    /// The collection of an `each` method, created by the first item added
+    /// to a field which does not hold it.
+    fn quote_each_collection(&self) -> proc_macro2::TokenStream {
         let field_ident = &self.ident;
 
-        let fn_ident = if let Some(each_ident) = self.attributes.get_each_ident() {
-            quote! { #each_ident }
-        } else {
-            quote! { #field_ident }
-        };
+        if self.holds_collection() {
+            return quote! { self.#field_ident };
+        }
 
-        let value_type = if self.is_optional() || self.has_each_method() {
-            let inner_type = inner_type(&self.ty).unwrap().clone();
-            quote! { #inner_type }
-        } else {
-            let field_type = &self.ty;
-            quote! { #field_type }
-        };
+        if let Some(default_fn) = self.quote_default_fn() {
+            return quote! { self.#field_ident.get_or_insert_with(#default_fn) };
+        }
 
-        let assignment = if self.has_each_method() {
-            quote! {
-                self.#field_ident.push(value);
+        match self.capacity() {
+            Some(capacity) => {
+                let collection_type = self.collection_type().unwrap();
+
+                quote! {
+                    self.#field_ident
+                        .get_or_insert_with(|| <#collection_type>::with_capacity(#capacity))
+                }
             }
-        } else {
-            quote! {
-                let _ = self.#field_ident.insert(value);
+            None => {
+                quote! { self.#field_ident.get_or_insert_with(std::default::Default::default) }
             }
-        };
+        }
+    }
 
-        let return_ty = if !self.attributes.get_validator_paths().is_empty() {
-            quote! { std::result::Result<&mut Self, #builder_error_ident> }
-        } else {
-            quote! { &mut Self }
-        };
+    /// The number of items an `each` collection is allocated for up front.
+    pub fn capacity(&self) -> std::option::Option<&syn::Expr> {
+        self.attributes
+            .get_capacity()
+            .filter(|_| self.has_each_method() && self.has_capacity_constructor())
+    }
 
-        let validation = if let Some(validator_path) = self.attributes.get_first_validator_path() {
-            quote! {
-                let value = #validator_path(value)?;
-            }
+    fn has_capacity_constructor(&self) -> bool {
+        matches!(
+            self.each_collection(),
+            Some(
+                EachCollection::Vec
+                    | EachCollection::VecDeque
+                    | EachCollection::SmallVec
+                    | EachCollection::String
+            )
+        ) || self.collection_type().is_some_and(|collection_type| {
+            is_container("HashSet", collection_type) || is_container("HashMap", collection_type)
+        })
+    }
+
+    /// The index the next item of an `each` method is added at, counting the
+    /// items of a default collection.
+    fn quote_each_len(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+
+        if self.has_default() {
+            let collection = self.quote_each_collection();
+
+            return quote! { #collection.len() };
+        }
+
+        if !self.holds_collection() {
+            quote! { self.#field_ident.as_ref().map_or(0, |collection| collection.len()) }
         } else {
-            quote! {}
-        };
+            quote! { self.#field_ident.len() }
+        }
+    }
 
-        let return_value = if !self.attributes.get_validator_paths().is_empty() {
-            quote! {
-                Ok(self)
-            }
+    /// Whether the collection of an `each` method already holds `value`, or
+    /// for a map its key.
+    fn quote_each_contains(&self) -> proc_macro2::TokenStream {
+        if self.is_sorted() {
+            self.quote_each_collection_check(quote! { binary_search(&value).is_ok() })
+        } else if self.has_each_entry_method() {
+            self.quote_each_collection_check(quote! { contains_key(&value.0) })
         } else {
-            quote! {
-                self
-            }
-        };
+            self.quote_each_collection_check(quote! { contains(&value) })
+        }
+    }
 
-        quote! {
-            pub fn #fn_ident(&mut self, value: impl std::convert::Into<#value_type>) -> #return_ty {
-                let value = value.into();
+    /// Calls the `check` method on the collection of an `each` method, which
+    /// is `false` for one which was not created yet.
+    fn quote_each_collection_check(
+        &self,
+        check: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
 
-                #validation
+        if self.holds_collection() || self.has_default() {
+            let collection = self.quote_each_collection();
 
-                #assignment
+            return quote! { #collection.#check };
+        }
 
-                #return_value
-            }
+        quote! {
+            self.#field_ident
+                .as_ref()
+                .is_some_and(|collection| collection.#check)
         }
     }
 
-    pub fn quote_missing_validator(&self) -> proc_macro2::TokenStream {
-        if self.is_optional() || self.is_vec() || self.has_default() {
-            return quote! {};
+    /// What the `each` method does with an item the collection already holds.
+    fn duplicate_policy(&self) -> std::option::Option<DuplicatePolicy> {
+        self.attributes
+            .get_duplicate_policy()
+            .filter(|_| self.has_each_method())
+    }
+
+    /// The item type of the groups of a `Vec<Vec<T>>`, whose `each` method
+    /// adds a whole group at a time.
+    fn group_item_type(&self) -> std::option::Option<&syn::Type> {
+        if !self.has_each_method() || self.each_collection() != Some(EachCollection::Vec) {
+            return None;
         }
 
-        let field_ident = &self.ident;
-        let field_ident_string = field_ident.to_string();
+        let group_type = item_type(EachCollection::Vec, self.collection_type()?)?;
 
-        quote! { missing_fields.add_if_none(#field_ident_string, &self.#field_ident); }
+        is_container("Vec", group_type)
+            .then(|| inner_type(group_type))
+            .flatten()
     }
 
-    pub fn quote_result_field(&self) -> proc_macro2::TokenStream {
-        let field_ident = &self.ident;
+    /// Whether the `each` method keeps a `Vec` sorted by inserting every item
+    /// at its place.
+    fn is_sorted(&self) -> bool {
+        self.attributes.is_sorted()
+            && self.has_each_method()
+            && self.each_collection() == Some(EachCollection::Vec)
+    }
 
-        if let Some(default_path) = self.attributes.get_default_path() {
-            return quote! {
-                #field_ident: self.#field_ident.clone().unwrap_or_else(#default_path),
-            };
-        }
+    /// Whether the `each` method takes a key and a value.
+    fn has_each_entry_method(&self) -> bool {
+        self.has_each_method() && self.each_collection() == Some(EachCollection::Map)
+    }
 
-        if self.is_optional() || self.has_each_method() {
-            return quote! {
-                #field_ident: self.#field_ident.clone(),
-            };
-        }
+    fn has_default(&self) -> bool {
+        self.attributes.get_default_path().is_some()
+    }
 
-        quote! {
-            #field_ident: self.#field_ident.clone().unwrap(),
+    fn treats_empty_as_missing(&self) -> bool {
+        if self.is_optional() {
+            return false;
         }
+
+        self.attributes.is_treat_empty_as_missing()
+            || (self.struct_attributes.is_treat_empty_as_missing()
+                && (is_string(&self.ty) || self.is_vec()))
     }
-}
 
-impl TryFrom<syn::Field> for TargetField {
-    type Error = syn::Error;
+    /// Whether `build()` cannot succeed until the field's setter is called.
+    pub fn is_required(&self) -> bool {
+        // collections are empty unless they are marked as `required`
+        !self.is_optional()
+            && !self.has_default()
+            && (self.attributes.is_required() || !(self.has_each_method() || self.is_vec()))
+    }
 
-    fn try_from(
-        ref field @ syn::Field {
-            ref ident,
-            ref attrs,
-            ref ty,
-            ..
-        }: syn::Field,
-    ) -> syn::Result<Self> {
-        let builder_attributes = attrs
-            .iter()
-            .cloned()
-            .flat_map(BuilderFieldAttributes::from)
-            .collect();
+    /// Whether this field alone can make `build()` fail.
+    pub fn can_fail_build(&self) -> bool {
+        let may_be_missing = self.is_required()
+            || (!self.is_optional() && !self.has_default() && self.treats_empty_as_missing());
 
-        Ok(Self {
-            ident: ident
-                .clone()
-                .ok_or_else(|| syn::Error::new(field.span(), "Unable to find field ident"))?,
-            ty: ty.clone(),
-            attributes: builder_attributes,
-        })
+        may_be_missing || self.can_fail_build_when_set()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    #![allow(unused_imports)]
-    use super::*;
+    /// Whether this field can make `build()` fail even once its setter was
+    /// called.
+    pub fn can_fail_build_when_set(&self) -> bool {
+        let may_be_empty =
+            !self.is_optional() && !self.has_default() && self.treats_empty_as_missing();
+
+        let has_build_checks = self.has_each_method()
+            && self
+                .attributes
+                .get_value_validations()
+                .iter()
+                .any(|validation| {
+                    matches!(
+                        validation,
+                        BuilderFieldAttribute::Len(_) | BuilderFieldAttribute::NonEmpty
+                    )
+                });
+
+        may_be_empty
+            || has_build_checks
+            || self.is_conditionally_required()
+            || (self.has_fallible_setter() && self.struct_attributes.is_defer_errors())
+    }
+
+    fn is_conditionally_required(&self) -> bool {
+        !self.attributes.get_required_if_idents().is_empty()
+            || !self.attributes.get_required_unless_idents().is_empty()
+    }
+
+    fn is_wrapped(&self) -> bool {
+        self.attributes.is_wrap() && smart_pointer_ident(&self.setter_value_type()).is_some()
+    }
+
+    pub fn quote_attr_errors(&self) -> proc_macro2::TokenStream {
+        let errors = self.attributes.0.iter().filter_map(|a| match a {
+            Ok(_) => std::option::Option::None,
+            Err(e) => e.to_compile_error().into(),
+        });
+
+        let wrap_error = (self.attributes.is_wrap() && !self.is_wrapped()).then(|| {
+            syn::Error::new(
+                self.ty.span(),
+                "`wrap` requires a field of type `Arc<T>`, `Rc<T>`, or `Box<T>`",
+            )
+            .to_compile_error()
+        });
+
+        let each_error = self
+            .attributes
+            .get_each_ident()
+            .filter(|_| self.each_collection().is_none())
+            .map(|each_ident| {
+                let message = if self.attributes.get_insert_with_ident().is_some() {
+                    "`each` with `insert_with` requires a collection with a single type parameter"
+                } else {
+                    "`each` requires a field of type `Vec<T>`, `VecDeque<T>`, `HashSet<T>`, `BTreeSet<T>`, `HashMap<K, V>`, `BTreeMap<K, V>`, or `String`"
+                };
+
+                syn::Error::new(each_ident.span(), message).to_compile_error()
+            });
+
+        let insert_with_error = self
+            .attributes
+            .get_insert_with_ident()
+            .filter(|_| self.attributes.get_each_ident().is_none())
+            .map(|insert_with_ident| {
+                syn::Error::new(insert_with_ident.span(), "`insert_with` requires `each`")
+                    .to_compile_error()
+            });
+
+        let skip_from_str_error = (self.attributes.is_skip_from_str()
+            && !self.struct_attributes.is_from_str())
+        .then(|| {
+            syn::Error::new(
+                self.ident.span(),
+                "`skip_from_str` requires `from_str` on the struct",
+            )
+            .to_compile_error()
+        });
+
+        let cache_default_error =
+            (self.attributes.is_cache_default() && !self.has_default()).then(|| {
+                syn::Error::new(self.ident.span(), "`cache_default` requires a `default`")
+                    .to_compile_error()
+            });
+
+        let dedup_error = self.attributes.get_duplicate_policy().and_then(|_| {
+            let message = if !self.has_each_method() {
+                "`dedup` requires `each`"
+            } else if self.each_collection() == Some(EachCollection::String) {
+                // `String::contains` would match any substring
+                "`dedup` cannot tell the items appended to a `String` apart"
+            } else {
+                return None;
+            };
+
+            Some(syn::Error::new(self.ident.span(), message).to_compile_error())
+        });
+
+        let separator_error = self
+            .attributes
+            .get_separator()
+            .filter(|_| {
+                !self.has_each_method() || self.each_collection() != Some(EachCollection::String)
+            })
+            .map(|separator| {
+                syn::Error::new(
+                    separator.span(),
+                    "`separator` requires `each` on a field of type `String`",
+                )
+                .to_compile_error()
+            });
+
+        let group_error = self
+            .attributes
+            .is_group()
+            .then(|| {
+                let message = if self.group_item_type().is_none() {
+                    "`group` requires `each` on a field of type `Vec<Vec<T>>`"
+                } else if self.duplicate_policy().is_some() {
+                    "`group` cannot be combined with `dedup`, which only compares whole groups"
+                } else if self.has_fallible_setter() {
+                    "`group` cannot add single items to a field which checks every group"
+                } else {
+                    return None;
+                };
+
+                Some(syn::Error::new(self.ident.span(), message).to_compile_error())
+            })
+            .flatten();
+
+        let sorted_error = self
+            .attributes
+            .is_sorted()
+            .then(|| {
+                let message = if !self.is_sorted() {
+                    "`sorted` requires `each` on a field of type `Vec<T>`"
+                } else if self.attributes.is_group() {
+                    "`sorted` cannot keep the items added by `group` in order"
+                } else {
+                    return None;
+                };
+
+                Some(syn::Error::new(self.ident.span(), message).to_compile_error())
+            })
+            .flatten();
+
+        let capacity_error = self.attributes.get_capacity().and_then(|capacity| {
+            let message = if !self.has_each_method() {
+                "`capacity` requires `each`"
+            } else if !self.has_capacity_constructor() {
+                "`capacity` requires a `Vec`, `VecDeque`, `HashSet`, or `HashMap`"
+            } else if self.struct_attributes.is_const_fn() {
+                "`capacity` cannot allocate in the `const fn` of `const_fn`"
+            } else {
+                return None;
+            };
+
+            Some(syn::Error::new(capacity.span(), message).to_compile_error())
+        });
+
+        let const_fn_error = (self.struct_attributes.is_const_fn()
+            && !self.is_const_creatable())
+        .then(|| {
+            syn::Error::new(
+                self.ty.span(),
+                "`const_fn` cannot create this collection in a `const fn`, use a `BTreeMap`, `BTreeSet`, or `Vec`",
+            )
+            .to_compile_error()
+        });
+
+        let required_error = (self.attributes.is_required()
+            && (self.is_optional() || !(self.is_vec() || self.has_each_method())))
+        .then(|| {
+            syn::Error::new(
+                self.ident.span(),
+                "`required` only applies to a collection, other fields are required unless they are an `Option`",
+            )
+            .to_compile_error()
+        });
+
+        let tristate_error = (self.attributes.is_tristate() && !self.is_optional()).then(|| {
+            syn::Error::new(
+                self.ty.span(),
+                "`tristate` requires a field of type `Option<T>`",
+            )
+            .to_compile_error()
+        });
+
+        let missing_message_error = self
+            .attributes
+            .get_missing_message()
+            .filter(|_| {
+                (self.is_optional() || self.has_default()) && !self.is_conditionally_required()
+            })
+            .map(|message| {
+                syn::Error::new(
+                    message.span(),
+                    "`missing_message` has no effect on a field which is never missing",
+                )
+                .to_compile_error()
+            });
+
+        let validate_item_error = (!self.attributes.get_item_validator_paths().is_empty()
+            && !self.has_each_method())
+        .then(|| {
+            syn::Error::new(
+                self.ident.span(),
+                "`validate_item` requires an `each` attribute on a `Vec` field",
+            )
+            .to_compile_error()
+        });
+
+        let has_validators = !self.attributes.get_item_validator_paths().is_empty()
+            || self.attributes.iter().any(|attr| {
+                matches!(
+                    attr,
+                    Ok(BuilderFieldAttribute::Validate(_)
+                        | BuilderFieldAttribute::ValidateWithName(_))
+                )
+            });
+
+        let display_errors_error =
+            (self.attributes.is_display_errors() && !has_validators).then(|| {
+                syn::Error::new(
+                self.ident.span(),
+                "`display_errors` requires `validate`, `validate_with_name`, or `validate_item`",
+            )
+            .to_compile_error()
+            });
+
+        let required_condition_error = (self.is_conditionally_required()
+            && !self.is_optional()
+            && !self.has_default())
+        .then(|| {
+            syn::Error::new(
+                self.ident.span(),
+                "`required_if` and `required_unless` require an `Option` field or a `default`",
+            )
+            .to_compile_error()
+        });
+
+        quote! {
+            #(#errors)*
+
+            #wrap_error
+
+            #each_error
+
+            #insert_with_error
+
+            #dedup_error
+
+            #capacity_error
+
+            #separator_error
+
+            #group_error
+
+            #sorted_error
+
+            #const_fn_error
+
+            #required_error
+
+            #tristate_error
+
+            #skip_from_str_error
+
+            #cache_default_error
+
+            #missing_message_error
+
+            #validate_item_error
+
+            #display_errors_error
+
+            #required_condition_error
+        }
+    }
+
+    pub fn quote_builder_field(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        let ty = &self.ty;
+
+        if self.is_tristate() {
+            return self.quote_tristate_field();
+        }
+
+        if self.is_optional() || self.holds_collection() {
+            return quote! { pub #ident: #ty, };
+        }
+
+        quote_spanned! {ty.span()=> pub #ident: std::option::Option<#ty>, }
+    }
+
+    fn quote_tristate_field(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        let tristate_ident = &self.tristate_ident;
+        let value_type = self.setter_value_type();
+
+        quote_spanned! {value_type.span()=> pub #ident: #tristate_ident<#value_type>, }
+    }
+
+    pub fn quote_clone_assertion(&self) -> proc_macro2::TokenStream {
+        let stored_type = self.stored_type();
+
+        quote_spanned! {stored_type.span()=>
+            assert_builder_field::<#stored_type>();
+        }
+    }
+
+    fn setter_ident(&self) -> syn::Ident {
+        self.attributes
+            .get_each_ident()
+            .unwrap_or(&self.ident)
+            .clone()
+    }
+
+    fn primary_setter_ident(&self) -> syn::Ident {
+        let fn_ident = self.setter_ident();
+
+        match smart_pointer_ident(&self.setter_value_type()) {
+            Some(pointer) if self.is_wrapped() && pointer == "Box" => {
+                format_ident!("{}_boxed", fn_ident, span = fn_ident.span())
+            }
+            Some(_) if self.is_wrapped() => {
+                format_ident!("{}_shared", fn_ident, span = fn_ident.span())
+            }
+            _ => fn_ident,
+        }
+    }
+
+    /// The type of the value a setter stores, a `(key, value)` tuple for a
+    /// map's `each` method.
+    fn setter_value_type(&self) -> syn::Type {
+        if self.has_each_entry_method() {
+            let (key_type, value_type) = map_types(self.collection_type().unwrap()).unwrap();
+
+            return syn::parse_quote!((#key_type, #value_type));
+        }
+
+        if self.has_each_method() {
+            let collection_type = self.collection_type().unwrap();
+
+            return item_type(self.each_collection().unwrap(), collection_type)
+                .unwrap()
+                .clone();
+        }
+
+        if self.is_optional() {
+            return inner_type(&self.ty).unwrap().clone();
+        }
+
+        self.ty.clone()
+    }
+
+    /// The parameters of a setter taking a value of `value_type`, split into
+    /// a key and a value for a map's `each` method.
+    fn quote_setter_params(&self, value_type: &syn::Type) -> proc_macro2::TokenStream {
+        if let Some((key_type, value_type)) = self
+            .collection_type()
+            .and_then(map_types)
+            .filter(|_| self.has_each_entry_method())
+        {
+            let key_param = self.quote_value_param(key_type);
+            let value_param = self.quote_value_param(value_type);
+
+            return quote! { key: #key_param, value: #value_param };
+        }
+
+        let value_param = self.quote_item_param(value_type);
+
+        quote! { value: #value_param }
+    }
+
+    /// The parameter type of a single item of an `each` method, any iterator
+    /// over the items of a group for a `Vec<Vec<T>>`.
+    fn quote_item_param(&self, value_type: &syn::Type) -> proc_macro2::TokenStream {
+        match self.group_item_type() {
+            Some(group_item_type) if !self.takes_concrete_value() => {
+                let group_item_param = self.quote_value_param(group_item_type);
+
+                quote! { impl std::iter::IntoIterator<Item = #group_item_param> }
+            }
+            _ => self.quote_value_param(value_type),
+        }
+    }
+
+    /// The arguments passing the parameters of `quote_setter_params()` on.
+    fn quote_setter_args(&self) -> proc_macro2::TokenStream {
+        if self.has_each_entry_method() {
+            quote! { key, value }
+        } else {
+            quote! { value }
+        }
+    }
+
+    /// Adds `value` to the collection of an `each` method.
+    fn quote_each_insert(&self) -> proc_macro2::TokenStream {
+        let collection = self.quote_each_collection();
+
+        match self.each_collection() {
+            Some(EachCollection::Map) => quote! {
+                let (key, value) = value;
+                #collection.insert(key, value);
+            },
+            Some(EachCollection::Vec) if self.is_sorted() => quote! {
+                let collection = &mut #collection;
+                let (std::result::Result::Ok(index) | std::result::Result::Err(index)) =
+                    collection.binary_search(&value);
+
+                collection.insert(index, value);
+            },
+            Some(EachCollection::VecDeque) => quote! {
+                #collection.push_back(value);
+            },
+            Some(EachCollection::Set) => quote! {
+                #collection.insert(value);
+            },
+            Some(EachCollection::String) => {
+                let separator = self.attributes.get_separator().map(|separator| {
+                    quote! {
+                        if !collection.is_empty() {
+                            collection.push_str(#separator);
+                        }
+                    }
+                });
+
+                quote! {
+                    let collection = &mut #collection;
+
+                    #separator
+
+                    collection.push_str(&value);
+                }
+            }
+            Some(EachCollection::Custom) => {
+                let insert_fn_ident = self.attributes.get_insert_with_ident();
+
+                quote! {
+                    #collection.#insert_fn_ident(value);
+                }
+            }
+            _ => quote! {
+                #collection.push(value);
+            },
+        }
+    }
+
+    fn has_fallible_setter(&self) -> bool {
+        !self.value_validations().is_empty()
+            || (self.has_each_method() && !self.attributes.get_item_validator_paths().is_empty())
+            || self.duplicate_policy() == Some(DuplicatePolicy::Error)
+            || (self.has_each_method() && self.each_collection() == Some(EachCollection::ArrayVec))
+    }
+
+    pub fn returns_result(&self) -> bool {
+        self.has_fallible_setter() && !self.struct_attributes.is_defer_errors()
+    }
+
+    fn quote_setter_attributes(&self) -> proc_macro2::TokenStream {
+        let track_caller = self
+            .has_fallible_setter()
+            .then(|| quote! { #[track_caller] });
+        let inline = self
+            .struct_attributes
+            .is_compact()
+            .then(|| quote! { #[inline(never)] });
+
+        quote! {
+            #track_caller
+            #inline
+        }
+    }
+
+    /// Whether the primary setter is a `const fn`, which rules out trait
+    /// calls, validation, and growing a collection.
+    fn has_const_setter(&self) -> bool {
+        self.struct_attributes.is_const_fn()
+            && !self.has_fallible_setter()
+            && !self.has_each_method()
+            && !self.struct_attributes.is_tracing()
+    }
+
+    /// Whether a `const fn` can create the field of an empty builder, which
+    /// rules out holding a collection without a `const fn new()`.
+    pub fn is_const_creatable(&self) -> bool {
+        !self.holds_collection()
+            || !["HashMap", "HashSet", "SmallVec"]
+                .into_iter()
+                .any(|ident| is_container(ident, &self.ty))
+    }
+
+    /// Whether the field can be read by a `const fn` build.
+    pub fn is_const_buildable(&self) -> bool {
+        // an unset plain `Vec` builds to an empty one via `Default`
+        !self.holds_collection()
+            && !self.is_tristate()
+            && !self.treats_empty_as_missing()
+            && (!self.is_vec() || self.is_required())
+    }
+
+    /// Whether setters take the value itself rather than `impl Into`.
+    fn takes_concrete_value(&self) -> bool {
+        self.struct_attributes.is_compact() || self.has_const_setter()
+    }
+
+    fn quote_value_param(&self, ty: impl quote::ToTokens) -> proc_macro2::TokenStream {
+        if self.takes_concrete_value() {
+            quote! { #ty }
+        } else {
+            quote! { impl std::convert::Into<#ty> }
+        }
+    }
+
+    fn quote_setter_return_ty(&self, builder_error_ident: &syn::Ident) -> proc_macro2::TokenStream {
+        if self.returns_result() {
+            quote! { std::result::Result<&mut Self, #builder_error_ident> }
+        } else {
+            quote! { &mut Self }
+        }
+    }
+
+    pub fn quote_setter(&self, builder_error_ident: &syn::Ident) -> proc_macro2::TokenStream {
+        let primary_setter = self.quote_primary_setter(builder_error_ident);
+        let cow_setter = self.quote_cow_setter(builder_error_ident);
+        let wrapping_setter = self.quote_wrapping_setter(builder_error_ident);
+        let duration_setters = self.quote_duration_setters(builder_error_ident);
+        let timestamp_setter = self.quote_timestamp_setter(builder_error_ident);
+        let tristate_setters = self.quote_tristate_setters();
+        let extend_setter = self.quote_extend_setter(builder_error_ident);
+        let group_setters = self.quote_group_setters();
+
+        quote! {
+            #primary_setter
+
+            #extend_setter
+
+            #group_setters
+
+            #tristate_setters
+
+            #cow_setter
+
+            #wrapping_setter
+
+            #duration_setters
+
+            #timestamp_setter
+        }
+    }
+
+    /// A by-value setter on the typestate builder which forwards to the
+    /// runtime builder and hands back `next`, the builder in its new state.
+    pub fn quote_typestate_setter(
+        &self,
+        builder_error_ident: &syn::Ident,
+        typestate_ident: &syn::Ident,
+        next: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let fn_ident = self.setter_ident();
+        let value_type = if self.is_wrapped() {
+            inner_type(&self.setter_value_type()).unwrap().clone()
+        } else {
+            self.setter_value_type()
+        };
+        let setter_params = self.quote_setter_params(&value_type);
+        let setter_args = self.quote_setter_args();
+        let setter_attributes = self.quote_setter_attributes();
+
+        let next_builder = quote! {
+            #typestate_ident {
+                builder: self.builder,
+                state: std::marker::PhantomData,
+            }
+        };
+
+        if self.returns_result() {
+            quote! {
+                #setter_attributes
+                pub fn #fn_ident(
+                    mut self,
+                    #setter_params,
+                ) -> std::result::Result<#next, #builder_error_ident> {
+                    self.builder.#fn_ident(#setter_args)?;
+
+                    std::result::Result::Ok(#next_builder)
+                }
+            }
+        } else {
+            quote! {
+                #setter_attributes
+                pub fn #fn_ident(mut self, #setter_params) -> #next {
+                    self.builder.#fn_ident(#setter_args);
+
+                    #next_builder
+                }
+            }
+        }
+    }
+
+    /// Adds every item of an iterator with the `each` method, named after
+    /// the field unless the `each` method already is.
+    fn quote_extend_setter(&self, builder_error_ident: &syn::Ident) -> proc_macro2::TokenStream {
+        if !self.has_each_method() {
+            return quote! {};
+        }
+
+        let field_ident = &self.ident;
+        let each_fn_ident = self.setter_ident();
+        let fn_ident = if each_fn_ident == *field_ident {
+            format_ident!("extend_{}", field_ident, span = field_ident.span())
+        } else {
+            field_ident.clone()
+        };
+
+        let (item_param, item, args) = match self.collection_type().and_then(map_types) {
+            Some((key_type, value_type)) if self.has_each_entry_method() => {
+                let key_param = self.quote_value_param(key_type);
+                let value_param = self.quote_value_param(value_type);
+
+                (
+                    quote! { (#key_param, #value_param) },
+                    quote! { (key, value) },
+                    quote! { key, value },
+                )
+            }
+            _ => {
+                let value_type = if self.is_wrapped() {
+                    inner_type(&self.setter_value_type()).unwrap().clone()
+                } else {
+                    self.setter_value_type()
+                };
+
+                (
+                    self.quote_item_param(&value_type),
+                    quote! { value },
+                    quote! { value },
+                )
+            }
+        };
+
+        let return_ty = self.quote_setter_return_ty(builder_error_ident);
+        let setter_attributes = self.quote_setter_attributes();
+
+        let (call, return_value) = if self.returns_result() {
+            (
+                quote! { self.#each_fn_ident(#args)?; },
+                quote! { std::result::Result::Ok(self) },
+            )
+        } else {
+            (quote! { self.#each_fn_ident(#args); }, quote! { self })
+        };
+
+        // setting a `required` collection counts even without any items
+        let mark_set = self.attributes.is_required().then(|| {
+            let collection = self.quote_each_collection();
+
+            quote! { #collection; }
+        });
+
+        quote! {
+            #setter_attributes
+            pub fn #fn_ident(
+                &mut self,
+                items: impl std::iter::IntoIterator<Item = #item_param>,
+            ) -> #return_ty {
+                #mark_set
+
+                for #item in items {
+                    #call
+                }
+
+                #return_value
+            }
+        }
+    }
+
+    /// With `group`, methods to start a new group of a `Vec<Vec<T>>` and to
+    /// add a single item to the last one, as an alternative to adding whole
+    /// groups with the `each` method.
+    fn quote_group_setters(&self) -> proc_macro2::TokenStream {
+        let Some(group_item_type) = self
+            .group_item_type()
+            .filter(|_| self.attributes.is_group())
+        else {
+            return quote! {};
+        };
+
+        let each_fn_ident = self.setter_ident();
+        let new_fn_ident = format_ident!("new_{}", each_fn_ident, span = each_fn_ident.span());
+        let item_fn_ident = format_ident!("{}_item", each_fn_ident, span = each_fn_ident.span());
+        let collection = self.quote_each_collection();
+        let value_param = self.quote_value_param(group_item_type);
+        let setter_attributes = self.quote_setter_attributes();
+
+        let value = if self.takes_concrete_value() {
+            quote! { value }
+        } else {
+            quote! { value.into() }
+        };
+
+        // an item added before any group was started opens the first one
+        quote! {
+            #setter_attributes
+            pub fn #new_fn_ident(&mut self) -> &mut Self {
+                #collection.push(std::vec::Vec::new());
+
+                self
+            }
+
+            #setter_attributes
+            pub fn #item_fn_ident(&mut self, value: #value_param) -> &mut Self {
+                let collection = &mut #collection;
+
+                match collection.last_mut() {
+                    std::option::Option::Some(group) => group.push(#value),
+                    std::option::Option::None => collection.push(std::vec![#value]),
+                }
+
+                self
+            }
+        }
+    }
+
+    fn quote_tristate_setters(&self) -> proc_macro2::TokenStream {
+        if !self.is_tristate() {
+            return quote! {};
+        }
+
+        let field_ident = &self.ident;
+        let tristate_ident = &self.tristate_ident;
+        let null_fn_ident = format_ident!("{}_null", field_ident, span = field_ident.span());
+        let unset_fn_ident = format_ident!("{}_unset", field_ident, span = field_ident.span());
+
+        quote! {
+            /// Sets the field to `None` explicitly, as opposed to leaving it
+            /// unset.
+            pub fn #null_fn_ident(&mut self) -> &mut Self {
+                self.#field_ident = #tristate_ident::Null;
+
+                self
+            }
+
+            /// Leaves the field unset again.
+            pub fn #unset_fn_ident(&mut self) -> &mut Self {
+                self.#field_ident = #tristate_ident::Unset;
+
+                self
+            }
+        }
+    }
+
+    /// The parameter of `new()` taking the field's value.
+    pub fn quote_new_param(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+        let value_type = if self.is_wrapped() {
+            inner_type(&self.setter_value_type()).unwrap().clone()
+        } else {
+            self.setter_value_type()
+        };
+        let value_param = self.quote_value_param(value_type);
+
+        quote! { #field_ident: #value_param }
+    }
+
+    /// Passes the parameter of `new()` to the setter of `builder`.
+    pub fn quote_new_set(&self, builder: &syn::Ident) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+        let fn_ident = self.setter_ident();
+
+        if self.returns_result() {
+            quote! { #builder.#fn_ident(#field_ident)?; }
+        } else {
+            quote! { #builder.#fn_ident(#field_ident); }
+        }
+    }
+
+    fn quote_forward_to_setter(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let fn_ident = self.setter_ident();
+
+        if self.returns_result() {
+            quote! { self.#fn_ident(#value) }
+        } else {
+            quote! { std::result::Result::Ok(self.#fn_ident(#value)) }
+        }
+    }
+
+    fn quote_duration_setters(&self, builder_error_ident: &syn::Ident) -> proc_macro2::TokenStream {
+        if !cfg!(feature = "duration") || !is_duration(&self.setter_value_type()) {
+            return quote! {};
+        }
+
+        let fn_ident = self.setter_ident();
+        let secs_fn_ident = format_ident!("{}_secs", fn_ident, span = fn_ident.span());
+        let millis_fn_ident = format_ident!("{}_millis", fn_ident, span = fn_ident.span());
+        let return_ty = self.quote_setter_return_ty(builder_error_ident);
+        let setter_attributes = self.quote_setter_attributes();
+
+        let humantime_setter = if cfg!(feature = "humantime") {
+            let str_fn_ident = format_ident!("{}_str", fn_ident, span = fn_ident.span());
+            let field_ident_string = self.ident.to_string();
+            let forward = self.quote_forward_to_setter(quote! { value });
+
+            quote! {
+                #[track_caller]
+                pub fn #str_fn_ident(
+                    &mut self,
+                    raw: &str,
+                ) -> std::result::Result<&mut Self, #builder_error_ident> {
+                    let location = std::panic::Location::caller();
+
+                    let value = ::humantime::parse_duration(raw).map_err(|err| {
+                        #builder_error_ident::invalid_field(
+                            #field_ident_string,
+                            format!("unable to parse `{raw}`: {err}"),
+                        )
+                        .with_source(err)
+                        .located_at(location)
+                    })?;
+
+                    #forward
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        quote! {
+            #setter_attributes
+            pub fn #secs_fn_ident(&mut self, secs: u64) -> #return_ty {
+                self.#fn_ident(std::time::Duration::from_secs(secs))
+            }
+
+            #setter_attributes
+            pub fn #millis_fn_ident(&mut self, millis: u64) -> #return_ty {
+                self.#fn_ident(std::time::Duration::from_millis(millis))
+            }
+
+            #humantime_setter
+        }
+    }
+
+    fn quote_timestamp_setter(&self, builder_error_ident: &syn::Ident) -> proc_macro2::TokenStream {
+        let value_type = self.setter_value_type();
+
+        // chrono only implements `Error` for its parse errors with its `std` feature
+        let (parse, source) = if cfg!(feature = "chrono") && is_chrono_date_time(&value_type) {
+            (
+                quote! { ::chrono::DateTime::parse_from_rfc3339(raw) },
+                quote! {},
+            )
+        } else if cfg!(feature = "time") && is_time_offset_date_time(&value_type) {
+            (
+                quote! {
+                    ::time::OffsetDateTime::parse(
+                        raw,
+                        &::time::format_description::well_known::Rfc3339,
+                    )
+                },
+                quote! { .with_source(err) },
+            )
+        } else {
+            return quote! {};
+        };
+
+        let fn_ident = self.setter_ident();
+        let rfc3339_fn_ident = format_ident!("{}_rfc3339", fn_ident, span = fn_ident.span());
+        let field_ident_string = self.ident.to_string();
+        let forward = self.quote_forward_to_setter(quote! { value });
+
+        quote! {
+            #[track_caller]
+            pub fn #rfc3339_fn_ident(
+                &mut self,
+                raw: &str,
+            ) -> std::result::Result<&mut Self, #builder_error_ident> {
+                let location = std::panic::Location::caller();
+
+                let value = #parse.map_err(|err| {
+                    #builder_error_ident::invalid_field(
+                        #field_ident_string,
+                        format!("unable to parse `{raw}`: {err}"),
+                    )
+                    #source
+                    .located_at(location)
+                })?;
+
+                #forward
+            }
+        }
+    }
+
+    fn quote_wrapping_setter(&self, builder_error_ident: &syn::Ident) -> proc_macro2::TokenStream {
+        if !self.is_wrapped() {
+            return quote! {};
+        }
+
+        let fn_ident = self.setter_ident();
+        let primary_fn_ident = self.primary_setter_ident();
+        let pointer_type = self.setter_value_type();
+        let pointee_type = inner_type(&pointer_type).unwrap();
+        let value_param = self.quote_value_param(pointee_type);
+        let return_ty = self.quote_setter_return_ty(builder_error_ident);
+        let setter_attributes = self.quote_setter_attributes();
+
+        quote! {
+            #setter_attributes
+            pub fn #fn_ident(&mut self, value: #value_param) -> #return_ty {
+                self.#primary_fn_ident(<#pointer_type>::new(value.into()))
+            }
+        }
+    }
+
+    fn quote_cow_setter(&self, builder_error_ident: &syn::Ident) -> proc_macro2::TokenStream {
+        if !is_static_str_cow(&self.setter_value_type()) {
+            return quote! {};
+        }
+
+        let fn_ident = self.setter_ident();
+        let owned_fn_ident = format_ident!("{}_owned", fn_ident, span = fn_ident.span());
+        let value_param = self.quote_value_param(quote! { std::string::String });
+        let return_ty = self.quote_setter_return_ty(builder_error_ident);
+        let setter_attributes = self.quote_setter_attributes();
+
+        quote! {
+            #setter_attributes
+            pub fn #owned_fn_ident(&mut self, value: #value_param) -> #return_ty {
+                self.#fn_ident(std::borrow::Cow::<'static, str>::Owned(value.into()))
+            }
+        }
+    }
+
+    fn quote_primary_setter(&self, builder_error_ident: &syn::Ident) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+        let fn_ident = self.primary_setter_ident();
+        let value_type = self.setter_value_type();
+
+        let assignment = if self.duplicate_policy() == Some(DuplicatePolicy::Ignore) {
+            let contains = self.quote_each_contains();
+            let insert = self.quote_each_insert();
+
+            quote! {
+                if !#contains {
+                    #insert
+                }
+            }
+        } else if self.has_each_method() {
+            self.quote_each_insert()
+        } else if self.is_tristate() {
+            let tristate_ident = &self.tristate_ident;
+
+            quote! {
+                self.#field_ident = #tristate_ident::Value(value);
+            }
+        } else if self.has_const_setter() {
+            quote! {
+                self.#field_ident = std::option::Option::Some(value);
+            }
+        } else {
+            quote! {
+                let _ = self.#field_ident.insert(value);
+            }
+        };
+
+        let return_ty = self.quote_setter_return_ty(builder_error_ident);
+        let setter_attributes = self.quote_setter_attributes();
+        let setter_params = self.quote_setter_params(&value_type);
+
+        let trace = if self.struct_attributes.is_tracing() {
+            let field_ident_string = self.ident.to_string();
+
+            quote! {
+                ::tracing::trace!(field = #field_ident_string, "setting builder field");
+            }
+        } else {
+            quote! {}
+        };
+
+        let value_validations = self.quote_value_validations(builder_error_ident);
+        let each_len = self.quote_each_len();
+
+        // the checks of an `each` field run on every pushed item, so their
+        // errors name the item rather than the whole field
+        let value_validation = if value_validations.is_empty() || !self.has_each_method() {
+            quote! { #(#value_validations)* }
+        } else {
+            let field_ident_string = field_ident.to_string();
+
+            quote! {
+                let value = (|| -> std::result::Result<#value_type, #builder_error_ident> {
+                    #(#value_validations)*
+
+                    std::result::Result::Ok(value)
+                })()
+                .map_err(|err| err.for_item(#field_ident_string, #each_len))?;
+            }
+        };
+
+        let item_validator_paths = if self.has_each_method() {
+            self.attributes.get_item_validator_paths()
+        } else {
+            vec![]
+        };
+
+        let item_validation = if item_validator_paths.is_empty() {
+            quote! {}
+        } else {
+            let field_ident_string = field_ident.to_string();
+
+            let validators = item_validator_paths.iter().map(|path| {
+                quote_spanned! {path.span()=>
+                    let validator: fn(#value_type) -> std::result::Result<#value_type, _> = #path;
+                }
+            });
+
+            let validator_error = self.quote_validator_error(builder_error_ident);
+
+            quote! {
+                let index = #each_len;
+
+                #(
+                    #validators
+
+                    let value = validator(value).map_err(|err| {
+                        #validator_error
+                            .for_item(#field_ident_string, index)
+                            .located_at(location)
+                    })?;
+                )*
+            }
+        };
+
+        let duplicate_check = if self.duplicate_policy() == Some(DuplicatePolicy::Error) {
+            let field_ident_string = field_ident.to_string();
+            let code = self.quote_validation_code("duplicate");
+            let contains = self.quote_each_contains();
+
+            // a map's entries have no index, so its error names the field
+            let error = if self.has_each_entry_method() {
+                quote! {
+                    #builder_error_ident::invalid_field(#field_ident_string, "duplicate key")
+                        .with_code(#code)
+                }
+            } else {
+                quote! {
+                    #builder_error_ident::invalid_field(#field_ident_string, "duplicate item")
+                        .with_code(#code)
+                        .for_item(#field_ident_string, #each_len)
+                }
+            };
+
+            quote! {
+                if #contains {
+                    return std::result::Result::Err(#error.located_at(location));
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let capacity_check = if self.has_each_method()
+            && self.each_collection() == Some(EachCollection::ArrayVec)
+        {
+            let field_ident_string = field_ident.to_string();
+            let code = self.quote_validation_code("capacity_exceeded");
+            let is_full = self.quote_each_collection_check(quote! { is_full() });
+
+            quote! {
+                if #is_full {
+                    return std::result::Result::Err(
+                        #builder_error_ident::invalid_field(#field_ident_string, "capacity exceeded")
+                            .with_code(#code)
+                            .for_item(#field_ident_string, #each_len)
+                            .located_at(location),
+                    );
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let validation = if !self.has_fallible_setter() {
+            quote! {}
+        } else if self.struct_attributes.is_defer_errors() {
+            quote! {
+                let location = std::panic::Location::caller();
+
+                let validated = (|| -> std::result::Result<#value_type, #builder_error_ident> {
+                    #value_validation
+
+                    #item_validation
+
+                    #duplicate_check
+
+                    #capacity_check
+
+                    std::result::Result::Ok(value)
+                })();
+
+                let value = match validated {
+                    std::result::Result::Ok(value) => value,
+                    std::result::Result::Err(err) => {
+                        self.__deferred_errors.push(err);
+
+                        return self;
+                    }
+                };
+            }
+        } else {
+            quote! {
+                let location = std::panic::Location::caller();
+
+                #value_validation
+
+                #item_validation
+
+                #duplicate_check
+
+                #capacity_check
+            }
+        };
+
+        let return_value = if self.returns_result() {
+            quote! {
+                Ok(self)
+            }
+        } else {
+            quote! {
+                self
+            }
+        };
+
+        let constness = self.has_const_setter().then(|| quote! { const });
+
+        if self.takes_concrete_value() {
+            let entry = self
+                .has_each_entry_method()
+                .then(|| quote! { let value = (key, value); });
+
+            return quote! {
+                #setter_attributes
+                pub #constness fn #fn_ident(&mut self, #setter_params) -> #return_ty {
+                    #entry
+
+                    #trace
+
+                    #validation
+
+                    #assignment
+
+                    #return_value
+                }
+            };
+        }
+
+        // only the conversion is generic, so the body is compiled once rather
+        // than for every type passed to the setter
+        let inner_fn_ident = format_ident!("__set_{}", fn_ident, span = fn_ident.span());
+
+        let value = if self.has_each_entry_method() {
+            quote! { (key.into(), value.into()) }
+        } else if self.group_item_type().is_some() {
+            quote! { value.into_iter().map(std::convert::Into::into).collect() }
+        } else {
+            quote! { value.into() }
+        };
+
+        quote! {
+            #[inline]
+            #setter_attributes
+            pub fn #fn_ident(&mut self, #setter_params) -> #return_ty {
+                self.#inner_fn_ident(#value)
+            }
+
+            #setter_attributes
+            fn #inner_fn_ident(&mut self, value: #value_type) -> #return_ty {
+                #trace
+
+                #validation
+
+                #assignment
+
+                #return_value
+            }
+        }
+    }
+
+    fn value_validations(&self) -> std::vec::Vec<&BuilderFieldAttribute> {
+        let validations = self.attributes.get_value_validations();
+
+        if !self.has_each_method() {
+            return validations;
+        }
+
+        // collection-level checks can only run once every item has been pushed
+        validations
+            .into_iter()
+            .filter(|validation| {
+                !matches!(
+                    validation,
+                    BuilderFieldAttribute::Len(_) | BuilderFieldAttribute::NonEmpty
+                )
+            })
+            .collect()
+    }
+
+    fn quote_validation_code(&self, default: &str) -> proc_macro2::TokenStream {
+        match self.attributes.get_code() {
+            Some(code) => quote! { #code },
+            None => quote! { #default },
+        }
+    }
+
+    /// Converts the `err` returned by a validator into the builder error,
+    /// through `From`, or through `Display` with `display_errors`.
+    fn quote_validator_error(&self, builder_error_ident: &syn::Ident) -> proc_macro2::TokenStream {
+        if !self.attributes.is_display_errors() {
+            return quote! { #builder_error_ident::from(err) };
+        }
+
+        let field_ident_string = self.ident.to_string();
+        let code = self.quote_validation_code("invalid_value");
+
+        quote! {
+            #builder_error_ident::invalid_field(
+                #field_ident_string,
+                std::string::ToString::to_string(&err),
+            )
+            .with_code(#code)
+        }
+    }
+
+    fn quote_value_validations(
+        &self,
+        builder_error_ident: &syn::Ident,
+    ) -> std::vec::Vec<proc_macro2::TokenStream> {
+        let field_ident_string = self.ident.to_string();
+        let value_type = self.setter_value_type();
+        let validator_error = self.quote_validator_error(builder_error_ident);
+
+        self.value_validations()
+            .into_iter()
+            .map(|validation| match validation {
+                BuilderFieldAttribute::Validate(path) => {
+                    let validator = quote_spanned! {path.span()=>
+                        let validator: fn(#value_type) -> std::result::Result<#value_type, _> = #path;
+                    };
+
+                    quote! {
+                        #validator
+
+                        let value = validator(value)
+                            .map_err(|err| #validator_error.located_at(location))?;
+                    }
+                }
+                BuilderFieldAttribute::ValidateWithName(path) => {
+                    let validator = quote_spanned! {path.span()=>
+                        let validator: fn(&'static str, #value_type) -> std::result::Result<#value_type, _> = #path;
+                    };
+
+                    quote! {
+                        #validator
+
+                        let value = validator(#field_ident_string, value)
+                            .map_err(|err| #validator_error.located_at(location))?;
+                    }
+                }
+                BuilderFieldAttribute::Check { predicate, message } => {
+                    let code = self.quote_validation_code("check_failed");
+
+                    quote! {
+                        if !(#predicate)(&value) {
+                            return std::result::Result::Err(
+                                #builder_error_ident::invalid_field(#field_ident_string, #message)
+                                    .with_code(#code)
+                                    .located_at(location),
+                            );
+                        }
+                    }
+                }
+                BuilderFieldAttribute::Range(range) => {
+                    let message = format!("must be in range {}", range_text(range));
+                    let code = self.quote_validation_code("out_of_range");
+
+                    quote! {
+                        if !(#range).contains(&value) {
+                            return std::result::Result::Err(
+                                #builder_error_ident::invalid_field(#field_ident_string, #message)
+                                    .with_code(#code)
+                                    .located_at(location),
+                            );
+                        }
+                    }
+                }
+                BuilderFieldAttribute::Len(range) => {
+                    let message = len_message(range);
+                    let code = self.quote_validation_code("invalid_length");
+
+                    quote! {
+                        if !(#range).contains(&value.len()) {
+                            return std::result::Result::Err(
+                                #builder_error_ident::invalid_field(#field_ident_string, #message)
+                                    .with_code(#code)
+                                    .located_at(location),
+                            );
+                        }
+                    }
+                }
+                BuilderFieldAttribute::Matches(pattern) => {
+                    let message = format!("must match `{}`", pattern.value());
+                    let code = self.quote_validation_code("pattern_mismatch");
+
+                    quote! {
+                        {
+                            static PATTERN: std::sync::LazyLock<::regex::Regex> =
+                                std::sync::LazyLock::new(|| ::regex::Regex::new(#pattern).unwrap());
+
+                            if !PATTERN.is_match(&value) {
+                                return std::result::Result::Err(
+                                    #builder_error_ident::invalid_field(#field_ident_string, #message)
+                                        .with_code(#code)
+                                        .located_at(location),
+                                );
+                            }
+                        }
+                    }
+                }
+                BuilderFieldAttribute::NonEmpty => {
+                    let code = self.quote_validation_code("empty");
+
+                    quote! {
+                        if value.is_empty() {
+                            return std::result::Result::Err(
+                                #builder_error_ident::invalid_field(#field_ident_string, "cannot be empty")
+                                    .with_code(#code)
+                                    .located_at(location),
+                            );
+                        }
+                    }
+                }
+                _ => quote! {},
+            })
+            .collect()
+    }
+
+    pub fn quote_build_checks(&self, builder_error_ident: &syn::Ident) -> proc_macro2::TokenStream {
+        if !self.has_each_method() {
+            return quote! {};
+        }
+
+        let field_ident = &self.ident;
+        let field_ident_string = field_ident.to_string();
+
+        let collection = if !self.holds_collection() {
+            quote! { collection }
+        } else {
+            quote! { self.#field_ident }
+        };
+
+        let checks = self
+            .attributes
+            .get_value_validations()
+            .into_iter()
+            .map(|validation| match validation {
+                BuilderFieldAttribute::Len(range) => {
+                    let message = len_message(range);
+
+                    let code = self.quote_validation_code("invalid_length");
+
+                    quote! {
+                        if !(#range).contains(&#collection.len()) {
+                            errors.push(
+                                #builder_error_ident::invalid_field(#field_ident_string, #message)
+                                    .with_code(#code),
+                            );
+                        }
+                    }
+                }
+                BuilderFieldAttribute::NonEmpty => {
+                    let code = self.quote_validation_code("empty");
+
+                    quote! {
+                        if #collection.is_empty() {
+                            errors.push(
+                                #builder_error_ident::invalid_field(#field_ident_string, "cannot be empty")
+                                    .with_code(#code),
+                            );
+                        }
+                    }
+                }
+                _ => quote! {},
+            });
+
+        // an untouched optional or required field has no items to check
+        if !self.holds_collection() {
+            return quote! {
+                if let std::option::Option::Some(collection) = &self.#field_ident {
+                    #(#checks)*
+                }
+            };
+        }
+
+        quote! {
+            #(#checks)*
+        }
+    }
+
+    pub fn stored_type(&self) -> &syn::Type {
+        if self.is_optional() {
+            return inner_type(&self.ty).unwrap();
+        }
+
+        &self.ty
+    }
+
+    pub fn quote_set_any_arm(&self, builder_error_ident: &syn::Ident) -> proc_macro2::TokenStream {
+        let field_ident_string = self.ident.to_string();
+        let fn_ident = self.primary_setter_ident();
+        let value_type = self.setter_value_type();
+
+        let (entry, args) = if self.has_each_entry_method() {
+            (quote! { let (key, value) = *value; }, quote! { key, value })
+        } else {
+            (quote! {}, quote! { *value })
+        };
+
+        let call = if self.returns_result() {
+            quote! { #entry self.#fn_ident(#args)?; }
+        } else {
+            quote! { #entry self.#fn_ident(#args); }
+        };
+
+        quote! {
+            #field_ident_string => {
+                let value = value.downcast::<#value_type>().map_err(|_| {
+                    #builder_error_ident::invalid_field(
+                        #field_ident_string,
+                        format!(
+                            "expected a value of type `{}`",
+                            std::any::type_name::<#value_type>()
+                        ),
+                    )
+                    .located_at(location)
+                })?;
+
+                #call
+            }
+        }
+    }
+
+    /// Whether `set_from_str` parses the value of the field, which rules out
+    /// collections other than a `String`, as well as the key and value of a
+    /// map's `each` method, which a single string cannot hold both of.
+    fn is_parsed_from_str(&self) -> bool {
+        if self.attributes.is_skip_from_str()
+            || self.has_each_entry_method()
+            || self.group_item_type().is_some()
+        {
+            return false;
+        }
+
+        self.has_each_method()
+            || self
+                .collection_type()
+                .and_then(each_collection)
+                .is_none_or(|collection| collection == EachCollection::String)
+    }
+
+    pub fn quote_set_from_str_arm(
+        &self,
+        builder_error_ident: &syn::Ident,
+    ) -> proc_macro2::TokenStream {
+        let field_ident_string = self.ident.to_string();
+
+        if !self.is_parsed_from_str() {
+            return quote! {
+                #field_ident_string => {
+                    return std::result::Result::Err(
+                        #builder_error_ident::invalid_field(
+                            #field_ident_string,
+                            "cannot be set from a string",
+                        )
+                        .located_at(location),
+                    );
+                }
+            };
+        }
+
+        let fn_ident = self.setter_ident();
+        let value_type = if self.is_wrapped() {
+            inner_type(&self.setter_value_type()).unwrap().clone()
+        } else {
+            self.setter_value_type()
+        };
+
+        let call = if self.returns_result() {
+            quote! { self.#fn_ident(value)?; }
+        } else {
+            quote! { self.#fn_ident(value); }
+        };
+
+        // a type without `FromStr` is reported on the field rather than the
+        // derive
+        let parse = quote_spanned! {value_type.span()=>
+            raw.parse::<#value_type>()
+        };
+
+        quote! {
+            #field_ident_string => {
+                let value = #parse.map_err(|err| {
+                    #builder_error_ident::invalid_field(
+                        #field_ident_string,
+                        format!("unable to parse `{raw}`: {err}"),
+                    )
+                    .located_at(location)
+                })?;
+
+                #call
+            }
+        }
+    }
+
+    pub fn quote_getter(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+        let fn_ident = format_ident!("get_{}", field_ident, span = field_ident.span());
+        let stored_type = self.stored_type();
+
+        // collections count as set once they hold an element, as in `has_*`
+
+        let value = if self.holds_collection() {
+            quote! { (!self.#field_ident.is_empty()).then_some(&self.#field_ident) }
+        } else if self.is_tristate() {
+            quote! { self.#field_ident.as_value() }
+        } else {
+            quote! { self.#field_ident.as_ref() }
+        };
+
+        quote! {
+            pub fn #fn_ident(&self) -> std::option::Option<&#stored_type> {
+                #value
+            }
+        }
+    }
+
+    pub fn quote_mut_getter(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+        let fn_ident = format_ident!("get_{}_mut", field_ident, span = field_ident.span());
+        let stored_type = self.stored_type();
+
+        let value = if self.holds_collection() {
+            quote! { (!self.#field_ident.is_empty()).then_some(&mut self.#field_ident) }
+        } else if self.is_tristate() {
+            quote! { self.#field_ident.as_value_mut() }
+        } else {
+            quote! { self.#field_ident.as_mut() }
+        };
+
+        quote! {
+            pub fn #fn_ident(&mut self) -> std::option::Option<&mut #stored_type> {
+                #value
+            }
+        }
+    }
+
+    pub fn quote_is_set(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+        let fn_ident = format_ident!("has_{}", field_ident, span = field_ident.span());
+
+        let value = if self.holds_collection() {
+            quote! { !self.#field_ident.is_empty() }
+        } else if self.is_tristate() {
+            quote! { !self.#field_ident.is_unset() }
+        } else {
+            quote! { self.#field_ident.is_some() }
+        };
+
+        quote! {
+            pub fn #fn_ident(&self) -> bool {
+                #value
+            }
+        }
+    }
+
+    pub fn quote_is_enabled(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+
+        // booleans switch a condition on when `true`, anything else when set
+
+        let value_type = self.setter_value_type();
+        let syn::Type::Path(value_type) = &value_type else {
+            return self.quote_is_set_call();
+        };
+
+        if self.has_each_method() || !value_type.path.is_ident("bool") {
+            return self.quote_is_set_call();
+        }
+
+        if self.is_tristate() {
+            return quote! { self.#field_ident.as_value().is_some_and(|value| *value) };
+        }
+
+        match self.quote_default_fn() {
+            Some(default_fn) if !self.is_optional() => {
+                quote! { self.#field_ident.unwrap_or_else(#default_fn) }
+            }
+            _ => quote! { self.#field_ident.unwrap_or(false) },
+        }
+    }
+
+    pub fn quote_is_set_call(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+        let fn_ident = format_ident!("has_{}", field_ident, span = field_ident.span());
+
+        quote! { self.#fn_ident() }
+    }
+
+    fn cached_default_fn_ident(&self) -> syn::Ident {
+        format_ident!("__default_{}", self.ident)
+    }
+
+    /// The function producing the default of the field, which goes through
+    /// the cache with `cache_default`.
+    fn quote_default_fn(&self) -> std::option::Option<proc_macro2::TokenStream> {
+        let default_path = self.attributes.get_default_path()?;
+
+        if !self.attributes.is_cache_default() {
+            return Some(quote! { #default_path });
+        }
+
+        let fn_ident = self.cached_default_fn_ident();
+
+        Some(quote! { Self::#fn_ident })
+    }
+
+    /// Computes the default once and hands out clones of it.
+    pub fn quote_cached_default_fn(&self) -> proc_macro2::TokenStream {
+        let Some(default_path) = self.attributes.get_default_path() else {
+            return quote! {};
+        };
+
+        if !self.attributes.is_cache_default() {
+            return quote! {};
+        }
+
+        let fn_ident = self.cached_default_fn_ident();
+        let ty = &self.ty;
+
+        quote_spanned! {ty.span()=>
+            fn #fn_ident() -> #ty {
+                static CACHED: std::sync::OnceLock<#ty> = std::sync::OnceLock::new();
+
+                CACHED.get_or_init(#default_path).clone()
+            }
+        }
+    }
+
+    pub fn quote_clearer(&self) -> proc_macro2::TokenStream {
+        if !self.attributes.is_clearable() {
+            return quote! {};
+        }
+
+        let field_ident = &self.ident;
+        let fn_ident = format_ident!("clear_{}", field_ident, span = field_ident.span());
+
+        quote! {
+            pub fn #fn_ident(&mut self) -> &mut Self {
+                self.#field_ident = std::default::Default::default();
+
+                self
+            }
+        }
+    }
+
+    pub fn quote_missing_validator(&self) -> proc_macro2::TokenStream {
+        if self.is_optional() || self.has_default() {
+            return quote! {};
+        }
+
+        let field_ident = &self.ident;
+        let mark_missing = self.quote_mark_missing();
+
+        if self.treats_empty_as_missing() {
+            let is_empty = if self.holds_collection() {
+                quote! { self.#field_ident.is_empty() }
+            } else {
+                quote! { self.#field_ident.as_ref().is_none_or(|value| value.is_empty()) }
+            };
+
+            return quote! {
+                if #is_empty {
+                    #mark_missing
+                }
+            };
+        }
+
+        if !self.is_required() {
+            return quote! {};
+        }
+
+        let index = self.index;
+
+        quote! { missing_fields.add_if_none(#index, &self.#field_ident); }
+    }
+
+    pub fn quote_mark_missing(&self) -> proc_macro2::TokenStream {
+        let index = self.index;
+
+        quote! { missing_fields.add(#index); }
+    }
+
+    /// The entry of the field in the name table of the missing fields set.
+    pub fn quote_missing_field_entry(&self) -> proc_macro2::TokenStream {
+        let field_ident_string = self.ident.to_string();
+        let type_name = type_name(self.stored_type());
+
+        let message = match self.attributes.get_missing_message() {
+            Some(message) => quote! { std::option::Option::Some(#message) },
+            None => quote! { std::option::Option::None },
+        };
+
+        quote! { (#field_ident_string, #type_name, #message) }
+    }
+
+    /// The unset builder field, allocating a collection with a `capacity`.
+    pub fn quote_unset_builder_field(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+
+        match self.capacity().filter(|_| self.holds_collection()) {
+            Some(capacity) => {
+                let ty = &self.ty;
+
+                quote! { #field_ident: <#ty>::with_capacity(#capacity), }
+            }
+            None => quote! { #field_ident: std::default::Default::default(), },
+        }
+    }
+
+    /// The unset builder field, spelled out for a `const fn` which cannot
+    /// call `Default::default()`.
+    pub fn quote_const_unset_builder_field(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+        let tristate_ident = &self.tristate_ident;
+
+        if self.holds_collection() {
+            let ty = &self.ty;
+
+            return quote! { #field_ident: <#ty>::new(), };
+        }
+
+        if self.is_tristate() {
+            return quote! { #field_ident: #tristate_ident::Unset, };
+        }
+
+        quote! { #field_ident: std::option::Option::None, }
+    }
+
+    /// The builder field set from the value of the target's field in `self`.
+    pub fn quote_seeded_builder_field(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+
+        if self.is_tristate() {
+            return quote! {
+                #field_ident: self.#field_ident.clone().into(),
+            };
+        }
+
+        if self.is_optional() || self.holds_collection() {
+            return quote! {
+                #field_ident: self.#field_ident.clone(),
+            };
+        }
+
+        quote! {
+            #field_ident: std::option::Option::Some(self.#field_ident.clone()),
+        }
+    }
+
+    /// Records a change when the field is set and differs from `target`.
+    pub fn quote_diff_field(&self, field_change_ident: &syn::Ident) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+        let field_ident_string = field_ident.to_string();
+
+        let (value, target_value, is_set) = if self.is_tristate() {
+            (
+                quote! { self.#field_ident.as_value() },
+                quote! { target.#field_ident.as_ref() },
+                quote! { !self.#field_ident.is_unset() },
+            )
+        } else if self.is_optional() {
+            (
+                quote! { &self.#field_ident },
+                quote! { &target.#field_ident },
+                quote! { self.#field_ident.is_some() },
+            )
+        } else if self.holds_collection() {
+            (
+                quote! { &self.#field_ident },
+                quote! { &target.#field_ident },
+                quote! { !self.#field_ident.is_empty() },
+            )
+        } else {
+            (
+                quote! { value },
+                quote! { &target.#field_ident },
+                quote! { true },
+            )
+        };
+
+        let change = quote! {
+            if #is_set && #value != #target_value {
+                changes.push(#field_change_ident {
+                    field: #field_ident_string,
+                    from: format!("{:?}", target.#field_ident),
+                    to: format!("{:?}", #value),
+                });
+            }
+        };
+
+        if self.is_optional() || self.holds_collection() {
+            return change;
+        }
+
+        quote! {
+            if let std::option::Option::Some(value) = &self.#field_ident {
+                #change
+            }
+        }
+    }
+
+    /// Unsets the field, keeping the allocation of an each-collection.
+    pub fn quote_reset_field(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+
+        if self.holds_collection() {
+            return quote! { self.#field_ident.clear(); };
+        }
+
+        if self.is_tristate() {
+            return quote! { self.#field_ident = std::default::Default::default(); };
+        }
+
+        quote! { self.#field_ident = std::option::Option::None; }
+    }
+
+    /// Overwrites the field in `self` when it is set in `other`.
+    pub fn quote_merge_field(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+
+        let is_set = if self.holds_collection() {
+            quote! { !other.#field_ident.is_empty() }
+        } else if self.is_tristate() {
+            quote! { !other.#field_ident.is_unset() }
+        } else {
+            quote! { other.#field_ident.is_some() }
+        };
+
+        quote! {
+            if #is_set {
+                self.#field_ident = other.#field_ident.clone();
+            }
+        }
+    }
+
+    /// Copies the field from `base` into `builder` when it was never set.
+    pub fn quote_fill_from_base(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+
+        if self.holds_collection() {
+            return quote! {
+                if builder.#field_ident.is_empty() {
+                    builder.#field_ident = base.#field_ident.clone();
+                }
+            };
+        }
+
+        if self.is_tristate() {
+            return quote! {
+                if builder.#field_ident.is_unset() {
+                    builder.#field_ident = base.#field_ident.clone().into();
+                }
+            };
+        }
+
+        if self.is_optional() {
+            return quote! {
+                if builder.#field_ident.is_none() {
+                    builder.#field_ident = base.#field_ident.clone();
+                }
+            };
+        }
+
+        quote! {
+            if builder.#field_ident.is_none() {
+                builder.#field_ident = std::option::Option::Some(base.#field_ident.clone());
+            }
+        }
+    }
+
+    /// The stored value of the field, as a build in the struct's mode reads it.
+    fn quote_built_value(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+
+        // an owned build moves the value out instead of cloning it, and a
+        // taking build leaves an empty slot behind
+        match self.struct_attributes.get_build_mode() {
+            BuildMode::Ref => quote! { self.#field_ident.clone() },
+            BuildMode::Owned => quote! { self.#field_ident },
+            BuildMode::Take if self.holds_collection() || self.is_tristate() => {
+                quote! { std::mem::take(&mut self.#field_ident) }
+            }
+            BuildMode::Take => quote! { self.#field_ident.take() },
+        }
+    }
+
+    pub fn quote_patch_struct_field(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        let ty = &self.ty;
+
+        if self.is_tristate() {
+            return self.quote_tristate_field();
+        }
+
+        if self.is_optional() {
+            return quote! { pub #ident: #ty, };
+        }
+
+        quote_spanned! {ty.span()=> pub #ident: std::option::Option<#ty>, }
+    }
+
+    /// Moves the field into the patch, an empty each-collection counting as
+    /// unset.
+    pub fn quote_patch_field(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+        let value = self.quote_built_value();
+
+        if self.holds_collection() {
+            return quote! {
+                #field_ident: {
+                    let value = #value;
+                    (!value.is_empty()).then_some(value)
+                },
+            };
+        }
+
+        quote! {
+            #field_ident: #value,
+        }
+    }
+
+    /// Overwrites the field of `target` when it is set in the patch.
+    pub fn quote_apply_patch_field(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+
+        if self.is_tristate() {
+            return quote! {
+                if !self.#field_ident.is_unset() {
+                    target.#field_ident = self.#field_ident.into();
+                }
+            };
+        }
+
+        if self.is_optional() {
+            return quote! {
+                if self.#field_ident.is_some() {
+                    target.#field_ident = self.#field_ident;
+                }
+            };
+        }
+
+        quote! {
+            if let std::option::Option::Some(value) = self.#field_ident {
+                target.#field_ident = value;
+            }
+        }
+    }
+
+    /// The field of the target as a `const fn` build reads it, without
+    /// cloning or calling a closure.
+    pub fn quote_const_result_field(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+
+        let value = match self.struct_attributes.get_build_mode() {
+            BuildMode::Take => quote! { self.#field_ident.take() },
+            _ => quote! { self.#field_ident },
+        };
+
+        if let Some(default_path) = self.attributes.get_default_path() {
+            return quote! {
+                #field_ident: match #value {
+                    std::option::Option::Some(value) => value,
+                    std::option::Option::None => #default_path(),
+                },
+            };
+        }
+
+        if self.is_optional() {
+            return quote! {
+                #field_ident: #value,
+            };
+        }
+
+        quote! {
+            #field_ident: #value.unwrap(),
+        }
+    }
+
+    pub fn quote_result_field(&self) -> proc_macro2::TokenStream {
+        let field_ident = &self.ident;
+        let value = self.quote_built_value();
+
+        if self.is_tristate() {
+            return quote! {
+                #field_ident: #value.into(),
+            };
+        }
+
+        if let Some(default_fn) = self.quote_default_fn() {
+            if self.treats_empty_as_missing() {
+                return quote! {
+                    #field_ident: #value
+                        .filter(|value| !value.is_empty())
+                        .unwrap_or_else(#default_fn),
+                };
+            }
+
+            return quote! {
+                #field_ident: #value.unwrap_or_else(#default_fn),
+            };
+        }
+
+        if self.is_optional() || self.holds_collection() {
+            return quote! {
+                #field_ident: #value,
+            };
+        }
+
+        if !self.is_required() {
+            return quote! {
+                #field_ident: #value.unwrap_or_default(),
+            };
+        }
+
+        quote! {
+            #field_ident: #value.unwrap(),
+        }
+    }
+}
+
+impl TryFrom<syn::Field> for TargetField {
+    type Error = syn::Error;
+
+    fn try_from(field: syn::Field) -> syn::Result<Self> {
+        let builder_attributes = field
+            .attrs
+            .iter()
+            .cloned()
+            .flat_map(BuilderFieldAttributes::from)
+            .collect();
+
+        Ok(Self {
+            ident: field
+                .ident
+                .clone()
+                .ok_or_else(|| syn::Error::new(field.span(), "Unable to find field ident"))?,
+            ty: field.ty,
+            attributes: builder_attributes,
+            struct_attributes: BuilderStructAttributes::default(),
+            tristate_ident: format_ident!("Tristate"),
+            index: 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+    use super::*;
+
+    fn attr_errors(field: syn::Field) -> String {
+        TargetField::try_from(field)
+            .unwrap()
+            .quote_attr_errors()
+            .to_string()
+    }
+
+    #[test]
+    fn _require_field_with_each_attribute_to_be_container_type() {
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(each = "tag")]
+            tags: u64
+        ));
+
+        assert!(error.contains("`each` requires a field of type `Vec<T>`"));
+
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(each = "tag")]
+            tags: Vec<String>
+        ));
+
+        assert!(error.is_empty());
+
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(each = "tag")]
+            tags: Option<Vec<String>>
+        ));
+
+        assert!(error.is_empty());
+    }
+
+    #[test]
+    fn _require_insert_with_to_come_with_each() {
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(insert_with = "add")]
+            tags: Bag<String>
+        ));
+
+        assert!(error.contains("`insert_with` requires `each`"));
+
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(each = "tag", insert_with = "add")]
+            tags: Bag<String>
+        ));
+
+        assert!(error.is_empty());
+    }
+
+    #[test]
+    fn _only_allow_required_on_collections() {
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(required)]
+            port: u16
+        ));
+
+        assert!(error.contains("`required` only applies to a collection"));
+
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(each = "alias", required)]
+            aliases: Vec<String>
+        ));
+
+        assert!(error.is_empty());
+    }
+
+    #[test]
+    fn _require_dedup_to_come_with_each() {
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(dedup)]
+            tags: Vec<String>
+        ));
+
+        assert!(error.contains("`dedup` requires `each`"));
+
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(each = "word", separator = " ", dedup)]
+            description: String
+        ));
+
+        assert!(error.contains("`dedup` cannot tell the items appended to a `String` apart"));
+
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(each = "tag", dedup = "error")]
+            tags: Vec<String>
+        ));
+
+        assert!(error.is_empty());
+    }
+
+    #[test]
+    fn _require_capacity_on_collections_which_preallocate() {
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(each = "tag", capacity = 8)]
+            tags: std::collections::BTreeSet<String>
+        ));
+
+        assert!(error.contains("`capacity` requires a `Vec`"));
+
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(each = "tag", capacity = 8)]
+            tags: std::collections::HashSet<String>
+        ));
+
+        assert!(error.is_empty());
+    }
+
+    #[test]
+    fn _require_group_on_nested_vec() {
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(each = "row", group)]
+            rows: Vec<String>
+        ));
+
+        assert!(error.contains("`group` requires `each` on a field of type `Vec<Vec<T>>`"));
+
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(each = "batch", group, dedup)]
+            batches: Vec<Vec<u64>>
+        ));
+
+        assert!(error.contains("`group` cannot be combined with `dedup`"));
+
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(each = "row", group)]
+            rows: Vec<Vec<String>>
+        ));
+
+        assert!(error.is_empty());
+    }
+
+    #[test]
+    fn _require_sorted_on_vec() {
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(each = "tag", sorted)]
+            tags: std::collections::VecDeque<String>
+        ));
+
+        assert!(error.contains("`sorted` requires `each` on a field of type `Vec<T>`"));
+
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(each = "tag", sorted)]
+            tags: Vec<String>
+        ));
+
+        assert!(error.is_empty());
+    }
+
+    #[test]
+    fn _require_from_str_for_skip_from_str() {
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(skip_from_str)]
+            proxy: Option<Proxy>
+        ));
+
+        assert!(error.contains("`skip_from_str` requires `from_str` on the struct"));
+    }
+
+    #[test]
+    fn _only_treat_std_duration_as_duration() {
+        for ty in [
+            syn::parse_quote!(Duration),
+            syn::parse_quote!(std::time::Duration),
+            syn::parse_quote!(::core::time::Duration),
+        ] {
+            assert!(is_duration(&ty));
+        }
+
+        for ty in [
+            syn::parse_quote!(chrono::Duration),
+            syn::parse_quote!(time::Duration),
+            syn::parse_quote!(Duration<u64>),
+        ] {
+            assert!(!is_duration(&ty));
+        }
+    }
+
+    #[test]
+    fn _require_tristate_field_to_be_option() {
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(tristate)]
+            nickname: String
+        ));
+
+        assert!(error.contains("`tristate` requires a field of type `Option<T>`"));
+
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(tristate)]
+            nickname: Option<String>
+        ));
+
+        assert!(error.is_empty());
+    }
+
+    #[test]
+    fn _require_a_default_to_cache() {
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(cache_default)]
+            headers: Vec<String>
+        ));
+
+        assert!(error.contains("`cache_default` requires a `default`"));
+
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(default = load_headers, cache_default)]
+            headers: Vec<String>
+        ));
+
+        assert!(error.is_empty());
+    }
+
+    #[test]
+    fn _require_a_validator_for_display_errors() {
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(display_errors, range = 1..=65535)]
+            port: u32
+        ));
+
+        assert!(error.contains("`display_errors` requires `validate`"));
+
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(display_errors, validate = parse_port)]
+            port: u32
+        ));
+
+        assert!(error.is_empty());
+    }
+
+    #[test]
+    fn _reject_missing_message_on_fields_which_are_never_missing() {
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(missing_message = "unused")]
+            port: Option<u16>
+        ));
+
+        assert!(error.contains("`missing_message` has no effect"));
+
+        let error = attr_errors(syn::parse_quote!(
+            #[builder(missing_message = "a port is required")]
+            port: u16
+        ));
+
+        assert!(error.is_empty());
+    }
+
+    #[test]
+    fn _should_find_the_item_types_of_inline_collections() {
+        let small_vec: syn::Type = syn::parse_quote!(SmallVec<[u8; 4]>);
+        let array_vec: syn::Type = syn::parse_quote!(ArrayVec<String, 8>);
+
+        let u8_type: syn::Type = syn::parse_quote!(u8);
+        let string_type: syn::Type = syn::parse_quote!(String);
+
+        assert_eq!(
+            item_type(EachCollection::SmallVec, &small_vec),
+            Some(&u8_type)
+        );
+        assert_eq!(
+            item_type(EachCollection::ArrayVec, &array_vec),
+            Some(&string_type)
+        );
+    }
+
+    #[test]
+    fn _should_render_ranges_with_their_bounds_intact() {
+        let cases: [(syn::Expr, &str); 5] = [
+            (syn::parse_quote!(1..=65535), "1..=65535"),
+            (syn::parse_quote!(1..MAX_WORKERS), "1..MAX_WORKERS"),
+            (syn::parse_quote!(0..=MAX as usize), "0..=MAX as usize"),
+            (
+                syn::parse_quote!(-5..u16::MAX as i32),
+                "-5..u16::MAX as i32",
+            ),
+            (syn::parse_quote!(1..), "1.."),
+        ];
+
+        for (range, expected) in cases {
+            assert_eq!(range_text(&range), expected);
+        }
+    }
+
+    #[test]
+    fn _should_render_compact_type_names() {
+        let cases: [(syn::Type, &str); 4] = [
+            (syn::parse_quote!(u16), "u16"),
+            (
+                syn::parse_quote!(std::collections::HashMap<String, Vec<u8>>),
+                "std::collections::HashMap<String, Vec<u8>>",
+            ),
+            (syn::parse_quote!(&'static str), "&'static str"),
+            (
+                syn::parse_quote!(Box<dyn Fn(u8) -> u8>),
+                "Box<dyn Fn(u8)->u8>",
+            ),
+        ];
+
+        for (ty, expected) in cases {
+            assert_eq!(type_name(&ty), expected);
+        }
+    }
 }
@@ -1,6 +1,9 @@
-use crate::{builder_attribute::BuilderStructAttributes, target_field::TargetField};
-use quote::quote;
-use syn::{self, spanned::Spanned};
+use crate::{
+    builder_attribute::{BuildMode, BuilderStructAttributes, SmartPointer, ValidationStage},
+    target_field::TargetField,
+};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{self, ext::IdentExt, spanned::Spanned};
 
 fn extract_fields_named(input: &syn::DeriveInput) -> syn::Result<&syn::FieldsNamed> {
     match &input.data {
@@ -26,6 +29,99 @@ fn extract_fields_named(input: &syn::DeriveInput) -> syn::Result<&syn::FieldsNam
     }
 }
 
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<std::vec::Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<std::vec::Vec<_>>();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+fn is_similar_name(name: &str, other: &str) -> bool {
+    name != other && edit_distance(name, other) <= (name.len() / 3).max(1)
+}
+
+fn upper_camel_case(ident: &syn::Ident) -> String {
+    ident
+        .unraw()
+        .to_string()
+        .split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
+fn expansions_dir() -> std::path::PathBuf {
+    let target_dir = std::env::var_os("CARGO_TARGET_DIR")
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| {
+            std::env::var_os("CARGO_MANIFEST_DIR")
+                .map(std::path::PathBuf::from)
+                .unwrap_or_default()
+                .join("target")
+        });
+
+    target_dir.join("turann-expansions")
+}
+
+#[cfg(feature = "debug-expansion")]
+fn render_expansion(expansion: &proc_macro2::TokenStream) -> String {
+    // fall back to the raw tokens if the expansion is not a valid file
+    syn::parse2::<syn::File>(expansion.clone())
+        .map(|file| prettyplease::unparse(&file))
+        .unwrap_or_else(|_| expansion.to_string())
+}
+
+#[cfg(not(feature = "debug-expansion"))]
+fn render_expansion(expansion: &proc_macro2::TokenStream) -> String {
+    expansion.to_string()
+}
+
+/// Where the struct is declared, so same-named structs in different modules
+/// are written to different files.
+fn expansion_location(struct_ident: &syn::Ident) -> String {
+    let span = struct_ident.span().unwrap();
+
+    format!("{}:{}:{}", span.file(), span.line(), span.column())
+}
+
+fn write_expansion(
+    dir: &std::path::Path,
+    struct_ident: &syn::Ident,
+    location: &str,
+    expansion: &proc_macro2::TokenStream,
+) -> std::io::Result<std::path::PathBuf> {
+    use std::hash::{Hash, Hasher};
+
+    let contents = render_expansion(expansion);
+
+    let mut hasher = std::hash::DefaultHasher::new();
+    location.hash(&mut hasher);
+    let path = dir.join(format!("{struct_ident}-{:016x}.rs", hasher.finish()));
+
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(&path, contents)?;
+
+    Ok(path)
+}
+
 #[derive(Debug)]
 pub struct TargetStruct {
     pub ident: syn::Ident,
@@ -36,166 +132,2505 @@ pub struct TargetStruct {
     pub attributes: BuilderStructAttributes,
 }
 
-impl TargetStruct {
-    fn quote_builder_struct(&self) -> proc_macro2::TokenStream {
-        let builder_ident = &self.builder_ident;
-        let builder_fields = self.fields.iter().map(TargetField::quote_builder_field);
+impl TargetStruct {
+    fn quote_builder_struct(&self) -> proc_macro2::TokenStream {
+        let builder_ident = &self.builder_ident;
+        let builder_error_ident = &self.builder_error_ident;
+        let builder_fields = self.fields.iter().map(TargetField::quote_builder_field);
+
+        let deferred_errors_field = if self.attributes.is_defer_errors() {
+            quote! { __deferred_errors: std::vec::Vec<#builder_error_ident>, }
+        } else {
+            quote! {}
+        };
+
+        let checkpoints_field = if self.has_checkpoints() {
+            quote! { __checkpoints: std::vec::Vec<Self>, }
+        } else {
+            quote! {}
+        };
+
+        let field_change_struct = self.quote_field_change_struct();
+
+        let patch_struct = self.quote_patch_struct();
+
+        let tristate_enum = self.quote_tristate_enum();
+
+        // collections with a `capacity` are allocated by a handwritten default
+        let (derive_default, default_impl) =
+            if self.fields.iter().any(|field| field.capacity().is_some()) {
+                let unset_fields = self
+                    .fields
+                    .iter()
+                    .map(TargetField::quote_unset_builder_field);
+                let deferred_errors_field = self
+                    .attributes
+                    .is_defer_errors()
+                    .then(|| quote! { __deferred_errors: std::vec::Vec::new(), });
+                let checkpoints_field = self
+                    .has_checkpoints()
+                    .then(|| quote! { __checkpoints: std::vec::Vec::new(), });
+
+                (
+                    quote! {},
+                    quote! {
+                        impl std::default::Default for #builder_ident {
+                            fn default() -> Self {
+                                Self {
+                                    #(#unset_fields)*
+                                    #deferred_errors_field
+                                    #checkpoints_field
+                                }
+                            }
+                        }
+                    },
+                )
+            } else {
+                (quote! { Default }, quote! {})
+            };
+
+        let mut field_idents = self
+            .fields
+            .iter()
+            .map(|field| field.ident.clone())
+            .collect::<std::vec::Vec<_>>();
+
+        if self.attributes.is_defer_errors() {
+            field_idents.push(format_ident!("__deferred_errors"));
+        }
+
+        if self.has_checkpoints() {
+            field_idents.push(format_ident!("__checkpoints"));
+        }
+
+        let clone_and_debug_impls =
+            self.quote_clone_and_debug_impls(builder_ident, &[], &field_idents);
+
+        quote! {
+            #[derive(#derive_default)]
+            pub struct #builder_ident {
+                #(#builder_fields)*
+                #deferred_errors_field
+                #checkpoints_field
+            }
+
+            #clone_and_debug_impls
+
+            #default_impl
+
+            #field_change_struct
+
+            #patch_struct
+
+            #tristate_enum
+        }
+    }
+
+    /// Bounds which keep the impls cloning the stored values from applying
+    /// when a field type is not `Clone`, so the only error is the one of
+    /// `quote_field_assertions()` on the field. Higher-ranked bounds are not
+    /// checked where they are declared.
+    fn quote_clone_bounds(&self) -> proc_macro2::TokenStream {
+        // a taking build exists so that field types need not be `Clone`
+        if self.attributes.get_build_mode() == BuildMode::Take {
+            return quote! {};
+        }
+
+        let stored_types = self.fields.iter().map(TargetField::stored_type);
+
+        quote! {
+            where #(for<'__builder> #stored_types: std::clone::Clone,)*
+        }
+    }
+
+    /// Handwritten `Clone` and `Debug` impls for `ident`, a type holding the
+    /// stored values in `field_idents`, which only apply when the field types
+    /// implement the trait, unlike derived ones which report every field.
+    fn quote_clone_and_debug_impls(
+        &self,
+        ident: &syn::Ident,
+        params: &[syn::Ident],
+        field_idents: &[syn::Ident],
+    ) -> proc_macro2::TokenStream {
+        let stored_types = self.fields.iter().map(TargetField::stored_type);
+        let field_names = field_idents.iter().map(syn::Ident::to_string);
+        let name = ident.to_string();
+        let ty = quote! { #ident<#(#params),*> };
+
+        let clone_impl = (self.attributes.get_build_mode() != BuildMode::Take).then(|| {
+            let clone_bounds = self.quote_clone_bounds();
+
+            quote! {
+                impl<#(#params),*> std::clone::Clone for #ty #clone_bounds {
+                    fn clone(&self) -> Self {
+                        Self {
+                            #(#field_idents: std::clone::Clone::clone(&self.#field_idents),)*
+                        }
+                    }
+                }
+            }
+        });
+
+        quote! {
+            #clone_impl
+
+            impl<#(#params),*> std::fmt::Debug for #ty
+            where
+                #(for<'__builder> #stored_types: std::fmt::Debug,)*
+            {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.debug_struct(#name)
+                        #(.field(#field_names, &self.#field_idents))*
+                        .finish()
+                }
+            }
+        }
+    }
+
+    fn quote_field_assertions(&self) -> proc_macro2::TokenStream {
+        if self.attributes.get_build_mode() == BuildMode::Take {
+            return quote! {};
+        }
+
+        let clone_assertions = self.fields.iter().map(TargetField::quote_clone_assertion);
+
+        // reports a missing `Clone` impl at the field rather than inside `build()`
+        quote! {
+            const _: fn() = || {
+                #[diagnostic::on_unimplemented(
+                    message = "`{Self}` does not implement `Clone`, which builder fields require",
+                    label = "`build()` clones the value of this field",
+                    note = "derive or implement `Clone` for `{Self}`, share it through an `Arc`, or move the values out with #[builder(build = \"take\")]"
+                )]
+                trait BuilderField {}
+
+                impl<T: std::clone::Clone> BuilderField for T {}
+
+                fn assert_builder_field<T: BuilderField>() {}
+
+                #(#clone_assertions)*
+            };
+        }
+    }
+
+    fn quote_builder_impl(&self) -> proc_macro2::TokenStream {
+        let builder_ident = &self.builder_ident;
+        let builder_error_ident = &self.builder_error_ident;
+
+        let field_setters = self
+            .fields
+            .iter()
+            .map(|field| field.quote_setter(builder_error_ident));
+
+        let field_getters = self.fields.iter().map(TargetField::quote_getter);
+
+        let field_mut_getters = self.fields.iter().map(TargetField::quote_mut_getter);
+
+        let field_is_set_checks = self.fields.iter().map(TargetField::quote_is_set);
+
+        let field_clearers = self.fields.iter().map(TargetField::quote_clearer);
+
+        let cached_default_fns = self.fields.iter().map(TargetField::quote_cached_default_fn);
+
+        let merge_fn = self.quote_merge_fn();
+
+        let diff_fn = self.quote_diff_fn();
+
+        let checkpoint_fns = self.quote_checkpoint_fns();
+
+        let reset_fn = self.quote_reset_fn();
+
+        let build_patch_fn = self.quote_build_patch_fn();
+
+        let set_any = self.quote_set_any();
+
+        let set_from_str = self.quote_set_from_str();
+
+        let build_fn = self.quote_build_fn();
+
+        let build_owned_fn = self.quote_build_owned_fn();
+
+        let build_unchecked_fn = self.quote_build_unchecked_fn();
+
+        let build_or_panic_fns = self.quote_build_or_panic_fns();
+
+        let build_pointer_fns = self.quote_build_pointer_fns();
+
+        let build_with_base_fn = self.quote_build_with_base_fn();
+
+        let build_async_fn = self.quote_build_async_fn();
+
+        let clone_bounds = self.quote_clone_bounds();
+
+        quote! {
+            impl #builder_ident #clone_bounds {
+                #(#field_setters)*
+
+                #(#field_getters)*
+
+                #(#field_mut_getters)*
+
+                #(#field_is_set_checks)*
+
+                #(#field_clearers)*
+
+                #(#cached_default_fns)*
+
+                #merge_fn
+
+                #diff_fn
+
+                #checkpoint_fns
+
+                #reset_fn
+
+                #build_patch_fn
+
+                #set_any
+
+                #set_from_str
+
+                #build_fn
+
+                #build_owned_fn
+
+                #build_unchecked_fn
+
+                #build_or_panic_fns
+
+                #build_pointer_fns
+
+                #build_with_base_fn
+
+                #build_async_fn
+            }
+        }
+    }
+
+    fn quote_typestate_builder(&self) -> proc_macro2::TokenStream {
+        if !self.attributes.is_typestate() {
+            return quote! {};
+        }
+
+        let struct_ident = &self.ident;
+        let builder_ident = &self.builder_ident;
+        let builder_error_ident = &self.builder_error_ident;
+        let typestate_ident = self.typestate_ident();
+        let (set_ident, unset_ident) = self.typestate_marker_idents();
+
+        let required_fields = self
+            .fields
+            .iter()
+            .filter(|field| field.is_required())
+            .collect::<std::vec::Vec<_>>();
+
+        // one type parameter per required field, either set or unset
+        let state_params = required_fields
+            .iter()
+            .map(|field| format_ident!("__{}", upper_camel_case(&field.ident)))
+            .collect::<std::vec::Vec<_>>();
+
+        let setters = self.fields.iter().map(|field| {
+            let next = match required_fields
+                .iter()
+                .position(|required| required.ident == field.ident)
+            {
+                Some(index) => {
+                    let next_states = state_params.iter().enumerate().map(|(i, param)| {
+                        if i == index {
+                            quote! { #set_ident }
+                        } else {
+                            quote! { #param }
+                        }
+                    });
+
+                    quote! { #typestate_ident<#(#next_states),*> }
+                }
+                None => quote! { Self },
+            };
+
+            field.quote_typestate_setter(builder_error_ident, &typestate_ident, next)
+        });
+
+        let all_set = required_fields.iter().map(|_| &set_ident);
+
+        let clone_bounds = self.quote_clone_bounds();
+        let clone_and_debug_impls = self.quote_clone_and_debug_impls(
+            &typestate_ident,
+            &state_params,
+            &[format_ident!("builder"), format_ident!("state")],
+        );
+
+        let build_return_ty = self.quote_build_return_ty(quote! { #struct_ident });
+
+        let build_receiver = match self.attributes.get_build_mode() {
+            BuildMode::Take => quote! { mut self },
+            BuildMode::Ref | BuildMode::Owned => quote! { self },
+        };
+
+        quote! {
+            /// A builder which tracks its required fields in its type, so
+            /// `build()` only exists once every one of them has been set.
+            pub struct #typestate_ident<#(#state_params),*> {
+                builder: #builder_ident,
+                state: std::marker::PhantomData<(#(#state_params,)*)>,
+            }
+
+            #clone_and_debug_impls
+
+            /// Marks a required field of the typestate builder which has been set.
+            #[derive(Clone, Copy, Debug)]
+            pub struct #set_ident;
+
+            /// Marks a required field of the typestate builder which is still unset.
+            #[derive(Clone, Copy, Debug)]
+            pub struct #unset_ident;
+
+            impl<#(#state_params),*> #typestate_ident<#(#state_params),*> #clone_bounds {
+                #(#setters)*
+
+                /// Gives up the compile-time tracking for the runtime-checked builder.
+                pub fn into_builder(self) -> #builder_ident {
+                    self.builder
+                }
+            }
+
+            impl #typestate_ident<#(#all_set),*> #clone_bounds {
+                pub fn build(#build_receiver) -> #build_return_ty {
+                    self.builder.build()
+                }
+            }
+        }
+    }
+
+    fn typestate_ident(&self) -> syn::Ident {
+        format_ident!("{}TypedBuilder", self.ident)
+    }
+
+    fn typestate_marker_idents(&self) -> (syn::Ident, syn::Ident) {
+        (
+            format_ident!("{}FieldSet", self.builder_ident),
+            format_ident!("{}FieldUnset", self.builder_ident),
+        )
+    }
+
+    /// Whether `build()` can never fail, in which case it returns the target
+    /// directly rather than a `Result`.
+    fn is_infallible(&self) -> bool {
+        !self.fields.iter().any(TargetField::can_fail_build) && !self.has_struct_checks()
+    }
+
+    /// Whether a struct-level check runs in `build()`, which can fail however
+    /// the fields are set.
+    fn has_struct_checks(&self) -> bool {
+        let attributes = &self.attributes;
+
+        !(attributes.get_validator_paths().is_empty()
+            && attributes.get_pre_validator_paths().is_empty()
+            && attributes.get_cross_validators().is_empty()
+            && attributes.get_at_least_one_of_groups().is_empty()
+            && !attributes.is_use_validator()
+            && !attributes.is_use_garde())
+    }
+
+    /// The return type of a build producing `output`, wrapped in a `Result`
+    /// unless the build cannot fail.
+    fn quote_build_return_ty(&self, output: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let builder_error_ident = &self.builder_error_ident;
+
+        if self.is_infallible() {
+            output
+        } else {
+            quote! { std::result::Result<#output, #builder_error_ident> }
+        }
+    }
+
+    /// Calls `build()` on `builder`, producing a `Result` even when the build
+    /// cannot fail.
+    fn quote_try_build(&self, builder: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let builder_error_ident = &self.builder_error_ident;
+
+        if self.is_infallible() {
+            quote! { std::result::Result::<_, #builder_error_ident>::Ok(#builder.build()) }
+        } else {
+            quote! { #builder.build() }
+        }
+    }
+
+    fn quote_tristate_enum(&self) -> proc_macro2::TokenStream {
+        let Some(field) = self.fields.iter().find(|field| field.is_tristate()) else {
+            return quote! {};
+        };
+
+        let tristate_ident = &field.tristate_ident;
+
+        quote! {
+            /// The state of a `tristate` field. `Null` clears the target's
+            /// field, while `Unset` leaves it alone in a patch.
+            #[derive(Clone, Debug, Default, PartialEq, Eq)]
+            pub enum #tristate_ident<T> {
+                #[default]
+                Unset,
+                Null,
+                Value(T),
+            }
+
+            impl<T> #tristate_ident<T> {
+                pub fn is_unset(&self) -> bool {
+                    matches!(self, Self::Unset)
+                }
+
+                pub fn as_value(&self) -> std::option::Option<&T> {
+                    match self {
+                        Self::Value(value) => std::option::Option::Some(value),
+                        _ => std::option::Option::None,
+                    }
+                }
+
+                pub fn as_value_mut(&mut self) -> std::option::Option<&mut T> {
+                    match self {
+                        Self::Value(value) => std::option::Option::Some(value),
+                        _ => std::option::Option::None,
+                    }
+                }
+            }
+
+            impl<T> std::convert::From<std::option::Option<T>> for #tristate_ident<T> {
+                fn from(value: std::option::Option<T>) -> Self {
+                    match value {
+                        std::option::Option::Some(value) => Self::Value(value),
+                        std::option::Option::None => Self::Null,
+                    }
+                }
+            }
+
+            impl<T> std::convert::From<#tristate_ident<T>> for std::option::Option<T> {
+                fn from(value: #tristate_ident<T>) -> Self {
+                    match value {
+                        #tristate_ident::Value(value) => std::option::Option::Some(value),
+                        _ => std::option::Option::None,
+                    }
+                }
+            }
+        }
+    }
+
+    fn patch_ident(&self) -> syn::Ident {
+        format_ident!("{}Patch", self.ident)
+    }
+
+    fn quote_patch_struct(&self) -> proc_macro2::TokenStream {
+        if !self.attributes.is_patch() {
+            return quote! {};
+        }
+
+        let struct_ident = &self.ident;
+        let patch_ident = self.patch_ident();
+        let patch_fields = self
+            .fields
+            .iter()
+            .map(TargetField::quote_patch_struct_field);
+        let apply_fields = self.fields.iter().map(TargetField::quote_apply_patch_field);
+
+        let field_idents = self
+            .fields
+            .iter()
+            .map(|field| field.ident.clone())
+            .collect::<std::vec::Vec<_>>();
+        let clone_and_debug_impls =
+            self.quote_clone_and_debug_impls(&patch_ident, &[], &field_idents);
+
+        quote! {
+            /// The fields set in a builder, see `build_patch()`. `None` leaves
+            /// a field unchanged.
+            #[derive(Default)]
+            pub struct #patch_ident {
+                #(#patch_fields)*
+            }
+
+            #clone_and_debug_impls
+
+            impl #patch_ident {
+                /// Overwrites every field of `target` which is set in the patch.
+                pub fn apply(self, target: &mut #struct_ident) {
+                    #(#apply_fields)*
+                }
+            }
+        }
+    }
+
+    fn quote_build_patch_fn(&self) -> proc_macro2::TokenStream {
+        if !self.attributes.is_patch() {
+            return quote! {};
+        }
+
+        let patch_ident = self.patch_ident();
+        let receiver = self.quote_build_receiver();
+        let patch_fields = self.fields.iter().map(TargetField::quote_patch_field);
+
+        quote! {
+            /// Collects the fields which were set, for a partial update of an
+            /// existing value. Nothing is required, so this never fails, and
+            /// defaults are not applied.
+            pub fn build_patch(#receiver) -> #patch_ident {
+                #patch_ident {
+                    #(#patch_fields)*
+                }
+            }
+        }
+    }
+
+    fn quote_reset_fn(&self) -> proc_macro2::TokenStream {
+        let reset_fields = self.fields.iter().map(TargetField::quote_reset_field);
+
+        let reset_deferred_errors = self
+            .attributes
+            .is_defer_errors()
+            .then(|| quote! { self.__deferred_errors.clear(); });
+
+        let reset_checkpoints = self
+            .has_checkpoints()
+            .then(|| quote! { self.__checkpoints.clear(); });
+
+        quote! {
+            /// Unsets every field, as in a new builder, so that one builder
+            /// can be reused. Collections keep their allocation.
+            pub fn reset(&mut self) -> &mut Self {
+                #(#reset_fields)*
+                #reset_deferred_errors
+                #reset_checkpoints
+
+                self
+            }
+        }
+    }
+
+    /// Whether the builder keeps a stack of checkpoints. A snapshot is a
+    /// clone, which a taking build does not have.
+    fn has_checkpoints(&self) -> bool {
+        self.attributes.is_checkpoint() && self.attributes.get_build_mode() != BuildMode::Take
+    }
+
+    fn quote_checkpoint_fns(&self) -> proc_macro2::TokenStream {
+        if !self.has_checkpoints() {
+            return quote! {};
+        }
+
+        quote! {
+            /// Saves the current state of the builder, to return to it with
+            /// `rollback()`. Checkpoints stack, each `rollback()` undoes the
+            /// changes since the latest one.
+            pub fn checkpoint(&mut self) -> &mut Self {
+                // snapshots leave the stack out, else each would copy every
+                // earlier one
+                let checkpoints = std::mem::take(&mut self.__checkpoints);
+                let snapshot = self.clone();
+                self.__checkpoints = checkpoints;
+                self.__checkpoints.push(snapshot);
+
+                self
+            }
+
+            /// Restores the state saved by the latest `checkpoint()` and
+            /// drops it. Returns `false`, leaving the builder unchanged, when
+            /// there is no checkpoint.
+            pub fn rollback(&mut self) -> bool {
+                let std::option::Option::Some(snapshot) = self.__checkpoints.pop() else {
+                    return false;
+                };
+
+                let checkpoints = std::mem::take(&mut self.__checkpoints);
+                *self = snapshot;
+                self.__checkpoints = checkpoints;
+
+                true
+            }
+        }
+    }
+
+    fn field_change_ident(&self) -> syn::Ident {
+        format_ident!("{}FieldChange", self.builder_ident)
+    }
+
+    fn quote_field_change_struct(&self) -> proc_macro2::TokenStream {
+        if !self.attributes.is_diff() {
+            return quote! {};
+        }
+
+        let field_change_ident = self.field_change_ident();
+
+        quote! {
+            /// A field which is set in the builder to a different value than
+            /// in an existing target, see `diff()`.
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct #field_change_ident {
+                pub field: &'static str,
+                /// The target's value, formatted with `Debug`.
+                pub from: std::string::String,
+                /// The builder's value, formatted with `Debug`.
+                pub to: std::string::String,
+            }
+        }
+    }
+
+    fn quote_diff_fn(&self) -> proc_macro2::TokenStream {
+        if !self.attributes.is_diff() {
+            return quote! {};
+        }
+
+        let struct_ident = &self.ident;
+        let field_change_ident = self.field_change_ident();
+        let field_diffs = self
+            .fields
+            .iter()
+            .map(|field| field.quote_diff_field(&field_change_ident));
+
+        quote! {
+            /// Lists the fields which are set in the builder to a different
+            /// value than in `target`. An empty list means building would not
+            /// change anything.
+            pub fn diff(&self, target: &#struct_ident) -> std::vec::Vec<#field_change_ident> {
+                let mut changes = std::vec::Vec::new();
+
+                #(#field_diffs)*
+
+                changes
+            }
+        }
+    }
+
+    fn quote_merge_fn(&self) -> proc_macro2::TokenStream {
+        // a taking build exists so that field types need not be `Clone`
+        if self.attributes.get_build_mode() == BuildMode::Take {
+            return quote! {};
+        }
+
+        let merged_fields = self.fields.iter().map(TargetField::quote_merge_field);
+
+        let merged_deferred_errors = if self.attributes.is_defer_errors() {
+            quote! {
+                self.__deferred_errors
+                    .extend(other.__deferred_errors.iter().cloned());
+            }
+        } else {
+            quote! {}
+        };
+
+        quote! {
+            /// Overwrites every field with its value in `other`, where it is
+            /// set there, to layer configuration sources on top of each other.
+            pub fn merge(&mut self, other: &Self) -> &mut Self {
+                #(#merged_fields)*
+
+                #merged_deferred_errors
+
+                self
+            }
+        }
+    }
+
+    fn quote_build_fn(&self) -> proc_macro2::TokenStream {
+        let struct_ident = &self.ident;
+        let builder_error_ident = &self.builder_error_ident;
+        let missing_fields_ident = &self.missing_fields_ident;
+
+        let missing_fields_validators =
+            self.fields.iter().map(TargetField::quote_missing_validator);
+
+        let conditional_missing_validators = self
+            .fields
+            .iter()
+            .map(|field| self.quote_conditional_missing_validator(field));
+
+        let similar_field_suggestions = self.quote_similar_field_suggestions();
+
+        let field_build_checks = self
+            .fields
+            .iter()
+            .map(|field| field.quote_build_checks(builder_error_ident))
+            .collect::<std::vec::Vec<_>>();
+
+        let result_fields = self.fields.iter().map(TargetField::quote_result_field);
+
+        let receiver = self.quote_build_receiver();
+
+        let build_span = if self.attributes.is_tracing() {
+            let struct_ident_string = struct_ident.to_string();
+
+            quote! {
+                let _span = ::tracing::trace_span!("build", target = #struct_ident_string).entered();
+            }
+        } else {
+            quote! {}
+        };
+
+        let at_least_one_of_checks = self
+            .attributes
+            .get_at_least_one_of_groups()
+            .into_iter()
+            .map(|fields| {
+                let is_set_fn_idents = fields
+                    .iter()
+                    .map(|field| format_ident!("has_{}", field, span = field.span()));
+                let message = format!(
+                    "at least one of {} must be set",
+                    fields
+                        .iter()
+                        .map(|field| format!("`{field}`"))
+                        .collect::<std::vec::Vec<_>>()
+                        .join(", ")
+                );
+
+                quote! {
+                    if !(false #(|| self.#is_set_fn_idents())*) {
+                        errors.push(#builder_error_ident::invalid_state(#message));
+                    }
+                }
+            })
+            .collect::<std::vec::Vec<_>>();
+
+        let pre_validators = self
+            .attributes
+            .get_pre_validator_paths()
+            .into_iter()
+            .map(|path| {
+                quote_spanned! {path.span()=>
+                    let validator: fn(&Self) -> std::result::Result<(), _> = #path;
+                }
+            })
+            .collect::<std::vec::Vec<_>>();
+
+        let cross_validations =
+            self.attributes
+                .get_cross_validators()
+                .into_iter()
+                .map(|(fields, with)| {
+                    let field_names = fields.iter().map(syn::Ident::to_string);
+                    let field_types = fields.iter().map(|ident| {
+                        self.fields
+                            .iter()
+                            .find(|field| &field.ident == ident)
+                            .map_or_else(|| quote! { _ }, |field| {
+                                let ty = &field.ty;
+
+                                quote! { #ty }
+                            })
+                    });
+
+                    let validator = quote_spanned! {with.span()=>
+                        let validator: fn(#(&#field_types),*) -> std::result::Result<(), _> = #with;
+                    };
+
+                    quote! {
+                        #validator
+
+                        if let std::result::Result::Err(err) = validator(#(&result.#fields),*) {
+                            errors.push(#builder_error_ident::invalid_fields(&[#(#field_names),*], err));
+                        }
+                    }
+                })
+                .collect::<std::vec::Vec<_>>();
+
+        let validator_crate_validation = if self.attributes.is_use_validator() {
+            quote! {
+                if let std::result::Result::Err(report) = ::validator::Validate::validate(&result) {
+                    let mut fields = report.errors().keys().cloned().collect::<std::vec::Vec<_>>();
+                    fields.sort();
+
+                    errors.push(
+                        #builder_error_ident::validation_failed(report.to_string()).with_fields(fields),
+                    );
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let garde_validation = if self.attributes.is_use_garde() {
+            quote! {
+                if let std::result::Result::Err(report) = ::garde::Validate::validate(&result) {
+                    errors.extend(report.iter().map(|(path, error)| {
+                        let path = path.to_string();
+
+                        if path.is_empty() {
+                            #builder_error_ident::validation_failed(error.to_string())
+                        } else {
+                            #builder_error_ident::invalid_field(path, error.to_string())
+                        }
+                    }));
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let (deferred_errors_check, rejected_fields) = if self.attributes.is_defer_errors() {
+            (
+                quote! {
+                    errors.extend(self.__deferred_errors.iter().cloned());
+                },
+                // a field whose setter failed is reported by that failure
+                // rather than as missing
+                quote! {
+                    for err in &self.__deferred_errors {
+                        if let #builder_error_ident::InvalidField { field_name, .. } = err {
+                            missing_fields.remove(field_name);
+                        }
+                    }
+                },
+            )
+        } else {
+            (quote! {}, quote! {})
+        };
+
+        let validators = self
+            .attributes
+            .get_validator_paths()
+            .into_iter()
+            .map(|path| {
+                quote_spanned! {path.span()=>
+                    let validator: fn(#struct_ident) -> std::result::Result<#struct_ident, _> = #path;
+                }
+            })
+            .collect::<std::vec::Vec<_>>();
+
+        let finalizers = self
+            .attributes
+            .get_finalizer_paths()
+            .into_iter()
+            .map(|path| {
+                quote_spanned! {path.span()=>
+                    let finalizer: fn(#struct_ident) -> #struct_ident = #path;
+                }
+            });
+
+        if self.is_infallible() {
+            return quote! {
+                /// Runs the checks of `build()` which do not need the target.
+                /// Nothing can fail here, it exists for symmetry with builders
+                /// which have checks.
+                pub fn validate(&self) -> std::result::Result<(), #builder_error_ident> {
+                    std::result::Result::Ok(())
+                }
+
+                pub fn build(#receiver) -> #struct_ident {
+                    #build_span
+
+                    let result = #struct_ident {
+                        #(#result_fields)*
+                    };
+
+                    #(
+                        #finalizers
+
+                        let result = finalizer(result);
+                    )*
+
+                    result
+                }
+            };
+        }
+
+        let has_post_construction_checks = !cross_validations.is_empty()
+            || self.attributes.is_use_validator()
+            || self.attributes.is_use_garde();
+
+        let order = self.attributes.get_validate_order();
+        let pre_runs_after_missing = order
+            .iter()
+            .position(|stage| *stage == ValidationStage::Pre)
+            > order
+                .iter()
+                .position(|stage| *stage == ValidationStage::Missing);
+
+        let pre_construction_stages = order.iter().map(|stage| match stage {
+            ValidationStage::Missing => quote! {
+                if let std::result::Result::Err(err) = &missing_fields {
+                    errors.push(err.clone());
+                }
+
+                #(#at_least_one_of_checks)*
+            },
+            ValidationStage::Fields => quote! {
+                #(#field_build_checks)*
+            },
+            // pre-validators may rely on every required field being set,
+            // unless they were explicitly ordered before the missing check
+            ValidationStage::Pre if pre_runs_after_missing => quote! {
+                if missing_fields.is_ok() {
+                    #(
+                        #pre_validators
+
+                        if let std::result::Result::Err(err) = validator(self) {
+                            errors.push(#builder_error_ident::from(err));
+                        }
+                    )*
+                }
+            },
+            ValidationStage::Pre => quote! {
+                #(
+                    #pre_validators
+
+                    if let std::result::Result::Err(err) = validator(self) {
+                        errors.push(#builder_error_ident::from(err));
+                    }
+                )*
+            },
+            ValidationStage::Cross | ValidationStage::Struct => quote! {},
+        });
+
+        let post_construction_stages = order.iter().map(|stage| match stage {
+            ValidationStage::Cross if has_post_construction_checks => quote! {
+                #(#cross_validations)*
+
+                #validator_crate_validation
+
+                #garde_validation
+
+                if !errors.is_empty() {
+                    return std::result::Result::Err(#builder_error_ident::multiple(errors));
+                }
+            },
+            // struct validators take the target by value and hand it on, so
+            // the first failure leaves nothing to run the others against
+            ValidationStage::Struct => quote! {
+                #(
+                    #validators
+
+                    let result = validator(result)?;
+                )*
+            },
+            _ => quote! {},
+        });
+
+        let post_construction_errors =
+            has_post_construction_checks.then(|| quote! { let mut errors = std::vec::Vec::new(); });
+
+        quote! {
+            /// Runs every check of `build()` which does not need the target:
+            /// deferred setter errors, missing fields, collection checks, and
+            /// pre-validators. Cross-field and struct validators still only
+            /// run in `build()`.
+            pub fn validate(&self) -> std::result::Result<(), #builder_error_ident> {
+                let mut missing_fields = #missing_fields_ident::default();
+
+                #(#missing_fields_validators)*
+
+                #(#conditional_missing_validators)*
+
+                #similar_field_suggestions
+
+                #rejected_fields
+
+                let missing_fields = missing_fields.as_builder_error();
+
+                let mut errors = std::vec::Vec::new();
+
+                // setter errors are reported along with everything else
+                #deferred_errors_check
+
+                #(#pre_construction_stages)*
+
+                if !errors.is_empty() {
+                    return std::result::Result::Err(#builder_error_ident::multiple(errors));
+                }
+
+                std::result::Result::Ok(())
+            }
+
+            pub fn build(#receiver) -> std::result::Result<#struct_ident, #builder_error_ident> {
+                #build_span
+
+                self.validate()?;
+
+                #post_construction_errors
+
+                let result = #struct_ident {
+                    #(#result_fields)*
+                };
+
+                #(#post_construction_stages)*
+
+                #(
+                    #finalizers
+
+                    let result = finalizer(result);
+                )*
+
+                std::result::Result::Ok(result)
+            }
+        }
+    }
+
+    /// The receiver of `build()`.
+    fn quote_build_receiver(&self) -> proc_macro2::TokenStream {
+        match self.attributes.get_build_mode() {
+            BuildMode::Ref => quote! { &self },
+            BuildMode::Owned => quote! { self },
+            BuildMode::Take => quote! { &mut self },
+        }
+    }
+
+    fn quote_conditional_missing_validator(&self, field: &TargetField) -> proc_macro2::TokenStream {
+        let conditions = field
+            .attributes
+            .get_required_if_idents()
+            .into_iter()
+            .map(|ident| (ident, true))
+            .chain(
+                field
+                    .attributes
+                    .get_required_unless_idents()
+                    .into_iter()
+                    .map(|ident| (ident, false)),
+            );
+
+        let mark_missing = field.quote_mark_missing();
+        let is_set = field.quote_is_set_call();
+
+        let conditions = conditions
+            .map(|(ident, required_if)| {
+                let Some(condition_field) = self.fields.iter().find(|field| &field.ident == ident)
+                else {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("unknown field `{ident}`"),
+                    ));
+                };
+
+                let is_enabled = condition_field.quote_is_enabled();
+
+                Ok(if required_if {
+                    quote! { #is_enabled }
+                } else {
+                    quote! { !#is_enabled }
+                })
+            })
+            .collect::<syn::Result<std::vec::Vec<_>>>();
+
+        match conditions {
+            Ok(conditions) if conditions.is_empty() => quote! {},
+            Ok(conditions) => quote! {
+                if (#(#conditions)||*) && !#is_set {
+                    #mark_missing
+                }
+            },
+            Err(err) => err.to_compile_error(),
+        }
+    }
+
+    /// Pairs of a field and another one with a similar name, which is likely
+    /// a typo for it when set while the field is missing.
+    fn similar_field_pairs(&self) -> std::vec::Vec<(&TargetField, &TargetField)> {
+        self.fields
+            .iter()
+            .flat_map(|field| {
+                self.fields
+                    .iter()
+                    .filter(|other| {
+                        is_similar_name(&field.ident.to_string(), &other.ident.to_string())
+                    })
+                    .map(move |other| (field, other))
+            })
+            .collect()
+    }
+
+    fn quote_similar_field_suggestions(&self) -> proc_macro2::TokenStream {
+        let suggestions =
+            self.similar_field_pairs()
+                .into_iter()
+                .enumerate()
+                .map(|(pair, (_, other))| {
+                    let other_is_set = other.quote_is_set_call();
+
+                    quote! {
+                        if #other_is_set {
+                            missing_fields.suggest(#pair);
+                        }
+                    }
+                });
+
+        quote! { #(#suggestions)* }
+    }
+
+    fn quote_build_owned_fn(&self) -> proc_macro2::TokenStream {
+        let struct_ident = &self.ident;
+        let builder_error_ident = &self.builder_error_ident;
+
+        // an infallible `build()` never hands the builder back, so it may
+        // consume it
+        if self.attributes.get_build_mode() == BuildMode::Owned && self.is_infallible() {
+            return quote! {
+                /// Consumes the builder, handing it back along with the error when
+                /// the build fails, so the offending fields can be fixed and the
+                /// build retried.
+                pub fn build_owned(
+                    self,
+                ) -> std::result::Result<#struct_ident, (Self, #builder_error_ident)> {
+                    std::result::Result::Ok(self.build())
+                }
+            };
+        }
+
+        // handing the builder back needs a copy when `build()` consumes it
+        let (receiver, build_call) = match self.attributes.get_build_mode() {
+            BuildMode::Ref => (quote! { self }, self.quote_try_build(quote! { self })),
+            BuildMode::Owned => (
+                quote! { self },
+                quote! { std::clone::Clone::clone(&self).build() },
+            ),
+            // values are only taken once the checks before construction
+            // pass, so a builder handed back after a failing cross-field or
+            // struct-level check is left empty
+            BuildMode::Take => (quote! { mut self }, self.quote_try_build(quote! { self })),
+        };
+
+        quote! {
+            /// Consumes the builder, handing it back along with the error when
+            /// the build fails, so the offending fields can be fixed and the
+            /// build retried.
+            pub fn build_owned(
+                #receiver,
+            ) -> std::result::Result<#struct_ident, (Self, #builder_error_ident)> {
+                match #build_call {
+                    std::result::Result::Ok(result) => std::result::Result::Ok(result),
+                    std::result::Result::Err(err) => std::result::Result::Err((self, err)),
+                }
+            }
+        }
+    }
+
+    fn quote_build_unchecked_fn(&self) -> proc_macro2::TokenStream {
+        // building in a constant needs `build_unchecked`
+        if !self.attributes.is_unchecked() && !self.attributes.is_const_fn() {
+            return quote! {};
+        }
+
+        let struct_ident = &self.ident;
+        let receiver = self.quote_build_receiver();
+
+        let is_const = self.attributes.is_const_fn()
+            && self.fields.iter().all(TargetField::is_const_buildable);
+
+        let result_fields = self.fields.iter().map(|field| {
+            if is_const {
+                field.quote_const_result_field()
+            } else {
+                field.quote_result_field()
+            }
+        });
+
+        // a `const fn` cannot call through a function pointer
+        let finalizers = self
+            .attributes
+            .get_finalizer_paths()
+            .into_iter()
+            .map(|path| {
+                if is_const {
+                    quote_spanned! {path.span()=>
+                        let finalizer = #path;
+                    }
+                } else {
+                    quote_spanned! {path.span()=>
+                        let finalizer: fn(#struct_ident) -> #struct_ident = #path;
+                    }
+                }
+            });
+
+        let constness = is_const.then(|| quote! { const });
+
+        quote! {
+            /// Builds the target without running any validation or checking
+            /// for missing fields, for callers which already guarantee both.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a required field has not been set.
+            pub #constness fn build_unchecked(#receiver) -> #struct_ident {
+                let result = #struct_ident {
+                    #(#result_fields)*
+                };
+
+                #(
+                    #finalizers
+
+                    let result = finalizer(result);
+                )*
+
+                result
+            }
+        }
+    }
+
+    fn quote_build_or_panic_fns(&self) -> proc_macro2::TokenStream {
+        let struct_ident = &self.ident;
+        let receiver = self.quote_build_receiver();
+        let try_build = self.quote_try_build(quote! { self });
+
+        quote! {
+            /// Builds the target, panicking with the formatted error when the
+            /// build fails. Meant for tests and prototypes.
+            #[track_caller]
+            pub fn build_or_panic(#receiver) -> #struct_ident {
+                match #try_build {
+                    std::result::Result::Ok(result) => result,
+                    std::result::Result::Err(err) => std::panic!("{err:#}"),
+                }
+            }
+
+            /// Builds the target, panicking with `message` followed by the
+            /// formatted error when the build fails.
+            #[track_caller]
+            pub fn expect_build(#receiver, message: &str) -> #struct_ident {
+                match #try_build {
+                    std::result::Result::Ok(result) => result,
+                    std::result::Result::Err(err) => std::panic!("{message}: {err:#}"),
+                }
+            }
+        }
+    }
+
+    fn quote_build_pointer_fns(&self) -> proc_macro2::TokenStream {
+        let struct_ident = &self.ident;
+        let receiver = self.quote_build_receiver();
+
+        let fns = self
+            .attributes
+            .get_build_pointers()
+            .into_iter()
+            .map(|pointer| {
+                let (fn_ident, pointer_path) = match pointer {
+                    SmartPointer::Arc => (format_ident!("build_arc"), quote! { std::sync::Arc }),
+                    SmartPointer::Rc => (format_ident!("build_rc"), quote! { std::rc::Rc }),
+                    SmartPointer::Box => (format_ident!("build_boxed"), quote! { std::boxed::Box }),
+                };
+
+                let return_ty = self.quote_build_return_ty(quote! { #pointer_path<#struct_ident> });
+                let body = if self.is_infallible() {
+                    quote! { #pointer_path::new(self.build()) }
+                } else {
+                    quote! { self.build().map(#pointer_path::new) }
+                };
+
+                quote! {
+                    pub fn #fn_ident(#receiver) -> #return_ty {
+                        #body
+                    }
+                }
+            });
+
+        quote! {
+            #(#fns)*
+        }
+    }
+
+    fn quote_build_with_base_fn(&self) -> proc_macro2::TokenStream {
+        if !self.attributes.is_base() {
+            return quote! {};
+        }
+
+        let struct_ident = &self.ident;
+        let receiver = self.quote_build_receiver();
+        let return_ty = self.quote_build_return_ty(quote! { #struct_ident });
+        let fills = self.fields.iter().map(TargetField::quote_fill_from_base);
+
+        let builder = match self.attributes.get_build_mode() {
+            BuildMode::Ref => quote! { let mut builder = self.clone(); },
+            BuildMode::Owned => quote! { let mut builder = self; },
+            BuildMode::Take => quote! { let builder = self; },
+        };
+
+        quote! {
+            /// Builds the target, taking every field which was not set from
+            /// `base`, like struct update syntax. Validation runs as usual.
+            pub fn build_with_base(#receiver, base: &#struct_ident) -> #return_ty {
+                #builder
+
+                #(#fills)*
+
+                builder.build()
+            }
+        }
+    }
+
+    fn quote_build_async_fn(&self) -> proc_macro2::TokenStream {
+        let struct_ident = &self.ident;
+        let builder_error_ident = &self.builder_error_ident;
+
+        let async_validator_paths = self.attributes.get_async_validator_paths();
+
+        if async_validator_paths.is_empty() {
+            return quote! {};
+        }
+
+        let receiver = self.quote_build_receiver();
+        let try_build = self.quote_try_build(quote! { self });
+
+        quote! {
+            pub async fn build_async(#receiver) -> std::result::Result<#struct_ident, #builder_error_ident> {
+                let result = #try_build?;
+
+                #(
+                    let result = #async_validator_paths(result).await?;
+                )*
+
+                std::result::Result::Ok(result)
+            }
+        }
+    }
+
+    fn quote_set_any(&self) -> proc_macro2::TokenStream {
+        let builder_error_ident = &self.builder_error_ident;
+
+        let set_any_arms = self
+            .fields
+            .iter()
+            .map(|field| field.quote_set_any_arm(builder_error_ident));
+
+        quote! {
+            #[track_caller]
+            pub fn set_any(
+                &mut self,
+                field: &str,
+                value: std::boxed::Box<dyn std::any::Any>,
+            ) -> std::result::Result<&mut Self, #builder_error_ident> {
+                let location = std::panic::Location::caller();
+
+                match field {
+                    #(#set_any_arms)*
+                    _ => {
+                        return std::result::Result::Err(
+                            #builder_error_ident::invalid_field(field.to_string(), "unknown field")
+                                .located_at(location),
+                        );
+                    }
+                }
+
+                std::result::Result::Ok(self)
+            }
+        }
+    }
+
+    fn quote_set_from_str(&self) -> proc_macro2::TokenStream {
+        if !self.attributes.is_from_str() {
+            return quote! {};
+        }
+
+        let builder_error_ident = &self.builder_error_ident;
+
+        let set_from_str_arms = self
+            .fields
+            .iter()
+            .map(|field| field.quote_set_from_str_arm(builder_error_ident));
+
+        quote! {
+            #[track_caller]
+            pub fn set_from_str(
+                &mut self,
+                field: &str,
+                raw: &str,
+            ) -> std::result::Result<&mut Self, #builder_error_ident> {
+                let location = std::panic::Location::caller();
+
+                match field {
+                    #(#set_from_str_arms)*
+                    _ => {
+                        return std::result::Result::Err(
+                            #builder_error_ident::invalid_field(field.to_string(), "unknown field")
+                                .located_at(location),
+                        );
+                    }
+                }
+
+                std::result::Result::Ok(self)
+            }
+        }
+    }
+
+    fn quote_missing_fields_block(&self) -> proc_macro2::TokenStream {
+        let missing_fields_ident = &self.missing_fields_ident;
+        let builder_error_ident = &self.builder_error_ident;
+
+        let field_count = self.fields.len();
+        let field_entries = self
+            .fields
+            .iter()
+            .map(TargetField::quote_missing_field_entry);
+
+        let similar_pairs = self.similar_field_pairs();
+        let pair_count = similar_pairs.len();
+        let pair_entries = similar_pairs.iter().map(|(field, other)| {
+            let (field_index, other_index) = (field.index, other.index);
+
+            quote! { (#field_index, #other_index) }
+        });
+
+        let remove_fn = self.attributes.is_defer_errors().then(|| {
+            quote! {
+                fn remove(&mut self, field_name: &str) -> &mut Self {
+                    if let std::option::Option::Some(index) =
+                        Self::FIELDS.iter().position(|(field, _, _)| *field == field_name)
+                    {
+                        self.missing[index / 64] &= !(1 << (index % 64));
+                    }
+
+                    self
+                }
+            }
+        });
+
+        // one bit per field and per pair, so tracking never allocates
+        let field_words = field_count.div_ceil(64);
+        let pair_words = pair_count.div_ceil(64);
+
+        quote! {
+            pub struct #missing_fields_ident {
+                missing: [u64; #field_words],
+                similar: [u64; #pair_words],
+            }
+
+            // arrays only implement `Default` up to 32 elements
+            impl std::default::Default for #missing_fields_ident {
+                fn default() -> Self {
+                    Self {
+                        missing: [0; #field_words],
+                        similar: [0; #pair_words],
+                    }
+                }
+            }
+
+            impl #missing_fields_ident {
+                /// The name, type name, and custom message of each field, by
+                /// index.
+                const FIELDS: [(&'static str, &'static str, std::option::Option<&'static str>); #field_count] = [
+                    #(#field_entries),*
+                ];
+
+                /// The indices of a field and of another one with a similar
+                /// name.
+                const SIMILAR: [(usize, usize); #pair_count] = [
+                    #(#pair_entries),*
+                ];
+
+                fn add(&mut self, index: usize) -> &mut Self {
+                    self.missing[index / 64] |= 1 << (index % 64);
+                    self
+                }
+
+                fn add_if_none<T>(&mut self, index: usize, field: &std::option::Option<T>) -> &mut Self {
+                    if field.is_none() {
+                        self.add(index);
+                    }
+
+                    self
+                }
+
+                #remove_fn
+
+                fn suggest(&mut self, pair: usize) -> &mut Self {
+                    self.similar[pair / 64] |= 1 << (pair % 64);
+                    self
+                }
+
+                fn is_missing(&self, index: usize) -> bool {
+                    self.missing[index / 64] & (1 << (index % 64)) != 0
+                }
+
+                fn is_suggested(&self, pair: usize) -> bool {
+                    self.similar[pair / 64] & (1 << (pair % 64)) != 0
+                }
+
+                fn as_builder_error(self) -> std::result::Result<(), #builder_error_ident> {
+                    if self.missing.iter().all(|word| *word == 0) {
+                        return Ok(());
+                    }
+
+                    let missing = (0..#field_count)
+                        .filter(|index| self.is_missing(*index))
+                        .collect::<Vec<_>>();
+                    let (with_message, without_message): (Vec<usize>, Vec<usize>) = missing
+                        .iter()
+                        .partition(|index| Self::FIELDS[**index].2.is_some());
+
+                    let missing_fields = (!without_message.is_empty()).then(|| {
+                        let described = without_message
+                            .iter()
+                            .map(|index| {
+                                let (field, type_name, _) = Self::FIELDS[*index];
+
+                                format!("{field}: {type_name}")
+                            })
+                            .collect::<Vec<_>>();
+                        let described = described.iter().map(String::as_str).collect::<Vec<_>>();
+
+                        let hints = Self::SIMILAR
+                            .iter()
+                            .enumerate()
+                            .filter(|(pair, (field, _))| {
+                                self.is_suggested(*pair) && without_message.contains(field)
+                            })
+                            .map(|(_, (field, set_field))| {
+                                format!(
+                                    "`{}` was set, did you mean `{}`?",
+                                    Self::FIELDS[*set_field].0,
+                                    Self::FIELDS[*field].0,
+                                )
+                            })
+                            .collect::<Vec<_>>();
+
+                        let mut error = #builder_error_ident::missing_fields(&described);
+                        if let #builder_error_ident::InvalidState { message, .. } = &mut error
+                            && !hints.is_empty()
+                        {
+                            *message = format!("{message} ({})", hints.join(" ")).into();
+                        }
+
+                        error
+                    });
+                    let missing_with_message = with_message.into_iter().map(|index| {
+                        let (field, _, message) = Self::FIELDS[index];
+
+                        #builder_error_ident::missing_field_with_message(field, message.unwrap_or_default())
+                    });
+
+                    Err(#builder_error_ident::multiple(missing_fields.into_iter().chain(missing_with_message)))
+                }
+            }
+        }
+    }
+
+    fn quote_builder_error_block(&self) -> proc_macro2::TokenStream {
+        let builder_error_ident = &self.builder_error_ident;
+        let report_ident = format_ident!("{}Report", builder_error_ident);
+        let report_entry_ident = format_ident!("{}ReportEntry", builder_error_ident);
+        let struct_ident_string = self.ident.to_string();
+
+        let error_derive_paths = self.attributes.get_error_derive_paths();
+        let error_derive = if error_derive_paths.is_empty() {
+            quote! {}
+        } else {
+            quote! { #[derive(#(#error_derive_paths),*)] }
+        };
+
+        // thiserror brings its own `Display` and `Error` impls, driven by
+        // `#[error(...)]` attributes on the variants
+        let uses_thiserror = error_derive_paths.iter().any(|path| {
+            path.segments
+                .iter()
+                .any(|segment| segment.ident == "thiserror")
+        });
+
+        let (invalid_state_format, invalid_field_format, validation_failed_format, multiple_format) =
+            self.attributes.get_error_display();
+        let span = proc_macro2::Span::call_site();
+        let invalid_state_format = invalid_state_format
+            .cloned()
+            .unwrap_or_else(|| syn::LitStr::new("Unable to build {target}: {message}", span));
+        let invalid_field_format = invalid_field_format.cloned().unwrap_or_else(|| {
+            syn::LitStr::new("Unable to assign field `{field_name}`: {message}", span)
+        });
+        let validation_failed_format = validation_failed_format
+            .cloned()
+            .unwrap_or_else(|| syn::LitStr::new("Unable to build {target}: {message}", span));
+        let multiple_format = multiple_format.map_or_else(
+            || syn::LitStr::new("Unable to build {target}: {count} errors{list}", span),
+            |format| syn::LitStr::new(&format!("{}{{list}}", format.value()), format.span()),
+        );
+
+        // `{target}` names the struct being built, optionally with its module
+        let target = if self.attributes.is_error_module_path() {
+            quote! { std::concat!(std::module_path!(), "::", #struct_ident_string) }
+        } else {
+            quote! { #struct_ident_string }
+        };
+
+        // thiserror rejects named arguments which the format does not use
+        let target_argument = |format: &syn::LitStr| {
+            format
+                .value()
+                .contains("{target")
+                .then(|| quote! { , target = #target })
+        };
+
+        let (
+            invalid_state_attribute,
+            invalid_field_attribute,
+            validation_failed_attribute,
+            multiple_attribute,
+        ) = if uses_thiserror {
+            let invalid_state_target = target_argument(&invalid_state_format);
+            let invalid_field_target = target_argument(&invalid_field_format);
+            let validation_failed_target = target_argument(&validation_failed_format);
+            let multiple_target = target_argument(&multiple_format);
+
+            (
+                quote! { #[error(#invalid_state_format #invalid_state_target)] },
+                quote! { #[error(#invalid_field_format #invalid_field_target)] },
+                quote! { #[error(#validation_failed_format #validation_failed_target)] },
+                quote! {
+                    #[error(
+                        #multiple_format #multiple_target,
+                        count = .errors.len(),
+                        list = #builder_error_ident::describe_errors(.errors, false),
+                    )]
+                },
+            )
+        } else {
+            (quote! {}, quote! {}, quote! {}, quote! {})
+        };
+
+        let message_formatter_path = self.attributes.get_message_formatter_path();
+
+        let display_and_error_impls = if let Some(path) = message_formatter_path
+            && uses_thiserror
+        {
+            syn::Error::new(
+                path.span(),
+                "`message_formatter` cannot be combined with a derived `thiserror::Error`",
+            )
+            .to_compile_error()
+        } else if uses_thiserror {
+            quote! {}
+        } else {
+            let display_body = match message_formatter_path {
+                Some(path) => {
+                    let formatter = quote_spanned! {path.span()=>
+                        let formatter: fn(&#builder_error_ident) -> std::string::String = #path;
+                    };
+
+                    quote! {
+                        #formatter
+
+                        f.write_str(&formatter(self))
+                    }
+                }
+                None => quote! {
+                    let target = #target;
+
+                    match self {
+                        #builder_error_ident::InvalidState { message, code } => {
+                            write!(f, #invalid_state_format)
+                        }
+                        #builder_error_ident::InvalidField {
+                            field_name,
+                            message,
+                            location,
+                            code,
+                            ..
+                        } => {
+                            write!(f, #invalid_field_format)?;
+
+                            // `{:#}` points at the setter call which failed
+                            match location {
+                                std::option::Option::Some(location) if f.alternate() => {
+                                    write!(f, " (at {location})")
+                                }
+                                _ => std::result::Result::Ok(()),
+                            }
+                        }
+                        #builder_error_ident::ValidationFailed {
+                            message,
+                            fields,
+                            code,
+                        } => write!(f, #validation_failed_format),
+                        #builder_error_ident::Multiple { errors } => {
+                            let count = errors.len();
+                            let list = #builder_error_ident::describe_errors(errors, f.alternate());
+
+                            write!(f, #multiple_format)
+                        }
+                    }
+                },
+            };
+
+            quote! {
+                impl std::fmt::Display for #builder_error_ident {
+                    #[allow(unused_variables)]
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        #display_body
+                    }
+                }
+
+                impl std::error::Error for #builder_error_ident {
+                    fn source(&self) -> std::option::Option<&(dyn std::error::Error + 'static)> {
+                        match self {
+                            #builder_error_ident::InvalidField {
+                                source: std::option::Option::Some(source),
+                                ..
+                            } => std::option::Option::Some(source.as_ref()),
+                            _ => std::option::Option::None,
+                        }
+                    }
+                }
+            }
+        };
+
+        quote! {
+            /// Occurs when the user either tries to incorrectly assign a field,
+            /// or when they attempt to build the target struct while the builder
+            /// is in an invalid state.
+            #[derive(Clone, Debug)]
+            #error_derive
+            pub enum #builder_error_ident {
+                /// Typically occurs on the `build()` method. Examples include:
+                /// missing fields, constraint violations, and illogical structs.
+                #invalid_state_attribute
+                InvalidState {
+                    message: std::borrow::Cow<'static, str>,
+                    /// A stable identifier for the failure, see `error_code()`.
+                    code: std::option::Option<&'static str>,
+                },
+                /// Typically occurs on the setter functions. Allows the builder
+                /// to catch problems before the user attempts to build the target.
+                #invalid_field_attribute
+                InvalidField {
+                    field_name: std::borrow::Cow<'static, str>,
+                    message: std::borrow::Cow<'static, str>,
+                    /// Where the failing setter was called, when known.
+                    location: std::option::Option<&'static std::panic::Location<'static>>,
+                    /// A stable identifier for the failure, see `error_code()`.
+                    code: std::option::Option<&'static str>,
+                    /// The underlying error which caused the failure, when known.
+                    source: std::option::Option<
+                        std::sync::Arc<dyn std::error::Error + std::marker::Send + std::marker::Sync>,
+                    >,
+                },
+                /// Occurs when the built value fails a struct-level validation,
+                /// such as a cross-field check, rather than missing a field.
+                #validation_failed_attribute
+                ValidationFailed {
+                    message: std::borrow::Cow<'static, str>,
+                    /// The fields involved in the failure, when known.
+                    fields: std::vec::Vec<std::borrow::Cow<'static, str>>,
+                    /// A stable identifier for the failure, see `error_code()`.
+                    code: std::option::Option<&'static str>,
+                },
+                /// Groups several errors which were collected before being
+                /// reported together.
+                #multiple_attribute
+                Multiple {
+                    errors: std::vec::Vec<#builder_error_ident>,
+                },
+            }
+
+            impl #builder_error_ident {
+                pub fn missing_fields(fields: &[&str]) -> Self {
+                    let missing_field_names = fields
+                        .iter()
+                        .map(|field_name| format!("`{field_name}`"))
+                        .reduce(|acc, next| format!("{acc}, {next}"))
+                        .unwrap_or_default();
+                    Self::InvalidState {
+                        message: format!("missing required field(s): {missing_field_names}").into(),
+                        code: std::option::Option::Some("missing_fields"),
+                    }
+                }
+
+                pub fn missing_field(field: &str) -> Self {
+                    Self::missing_fields(&[field])
+                }
+
+                pub fn missing_field_with_message(
+                    field: impl std::convert::Into<std::borrow::Cow<'static, str>>,
+                    message: impl std::convert::Into<std::borrow::Cow<'static, str>>,
+                ) -> Self {
+                    Self::InvalidField {
+                        field_name: field.into(),
+                        message: message.into(),
+                        location: std::option::Option::None,
+                        code: std::option::Option::Some("missing_fields"),
+                        source: std::option::Option::None,
+                    }
+                }
+
+                pub fn invalid_state(
+                    message: impl std::convert::Into<std::borrow::Cow<'static, str>>,
+                ) -> Self {
+                    Self::InvalidState {
+                        message: message.into(),
+                        code: std::option::Option::None,
+                    }
+                }
+
+                pub fn invalid_fields(fields: &[&str], message: impl std::fmt::Display) -> Self {
+                    let field_names = fields
+                        .iter()
+                        .map(|field_name| format!("`{field_name}`"))
+                        .reduce(|acc, next| format!("{acc}, {next}"))
+                        .unwrap_or_default();
+                    Self::ValidationFailed {
+                        message: format!("invalid field(s) {field_names}: {message}").into(),
+                        fields: fields.iter().map(|field| field.to_string().into()).collect(),
+                        code: std::option::Option::Some("invalid_fields"),
+                    }
+                }
+
+                pub fn validation_failed(
+                    message: impl std::convert::Into<std::borrow::Cow<'static, str>>,
+                ) -> Self {
+                    Self::ValidationFailed {
+                        message: message.into(),
+                        fields: std::vec::Vec::new(),
+                        code: std::option::Option::None,
+                    }
+                }
+
+                pub fn with_fields<F>(mut self, involved: impl std::iter::IntoIterator<Item = F>) -> Self
+                where
+                    F: std::convert::Into<std::borrow::Cow<'static, str>>,
+                {
+                    if let Self::ValidationFailed { fields, .. } = &mut self {
+                        fields.extend(involved.into_iter().map(std::convert::Into::into));
+                    }
+
+                    self
+                }
+
+                pub fn invalid_field(
+                    field_name: impl std::convert::Into<std::borrow::Cow<'static, str>>,
+                    message: impl std::convert::Into<std::borrow::Cow<'static, str>>,
+                ) -> Self {
+                    Self::InvalidField {
+                        field_name: field_name.into(),
+                        message: message.into(),
+                        location: std::option::Option::None,
+                        code: std::option::Option::None,
+                        source: std::option::Option::None,
+                    }
+                }
+
+                pub fn multiple(errors: impl std::iter::IntoIterator<Item = Self>) -> Self {
+                    let mut errors: std::vec::Vec<Self> = errors
+                        .into_iter()
+                        .flat_map(|error| match error {
+                            Self::Multiple { errors } => errors,
+                            error => vec![error],
+                        })
+                        .collect();
+
+                    if errors.len() == 1 {
+                        return errors.remove(0);
+                    }
+
+                    Self::Multiple { errors }
+                }
+
+                pub fn for_item(mut self, field: &str, index: usize) -> Self {
+                    if let Self::InvalidField { field_name, .. } = &mut self {
+                        *field_name = format!("{field}[{index}]").into();
+                    }
+
+                    self
+                }
+
+                pub fn with_source(
+                    mut self,
+                    err: impl std::error::Error + std::marker::Send + std::marker::Sync + 'static,
+                ) -> Self {
+                    if let Self::InvalidField { source, .. } = &mut self {
+                        *source = std::option::Option::Some(std::sync::Arc::new(err));
+                    }
+
+                    self
+                }
+
+                pub fn with_code(mut self, error_code: &'static str) -> Self {
+                    if let Self::InvalidState { code, .. }
+                    | Self::InvalidField { code, .. }
+                    | Self::ValidationFailed { code, .. } = &mut self
+                    {
+                        *code = std::option::Option::Some(error_code);
+                    }
+
+                    self
+                }
+
+                /// A stable, machine-readable identifier for the failure, so
+                /// callers can map errors without matching on their messages.
+                pub fn error_code(&self) -> &'static str {
+                    match self {
+                        Self::InvalidState { code, .. } => code.unwrap_or("invalid_state"),
+                        Self::InvalidField { code, .. } => code.unwrap_or("invalid_field"),
+                        Self::ValidationFailed { code, .. } => code.unwrap_or("validation_failed"),
+                        Self::Multiple { .. } => "multiple",
+                    }
+                }
+
+                #[allow(dead_code)]
+                /// Lists grouped errors on one line, or one per line with `{:#}`.
+                fn describe_errors(errors: &[Self], alternate: bool) -> std::string::String {
+                    if alternate {
+                        return errors.iter().map(|error| format!("\n  - {error:#}")).collect();
+                    }
+
+                    let errors = errors
+                        .iter()
+                        .map(std::string::ToString::to_string)
+                        .collect::<std::vec::Vec<_>>();
+
+                    format!(" ({})", errors.join("; "))
+                }
+
+                pub fn located_at(mut self, caller: &'static std::panic::Location<'static>) -> Self {
+                    if let Self::InvalidField { location, .. } = &mut self {
+                        *location = std::option::Option::Some(caller);
+                    }
+
+                    self
+                }
+
+                /// Flattens the error into one entry per failure, in a shape meant
+                /// to be handed to clients, such as the body of an HTTP response.
+                pub fn to_report(&self) -> #report_ident {
+                    let mut errors = std::vec::Vec::new();
+                    self.collect_report_entries(&mut errors);
+
+                    #report_ident { errors }
+                }
+
+                fn collect_report_entries(&self, entries: &mut std::vec::Vec<#report_entry_ident>) {
+                    let entry = |field, kind, message: &std::borrow::Cow<'static, str>| {
+                        #report_entry_ident {
+                            field,
+                            kind,
+                            code: self.error_code(),
+                            message: message.clone(),
+                        }
+                    };
+
+                    match self {
+                        Self::InvalidState { message, .. } => {
+                            entries.push(entry(std::option::Option::None, "invalid_state", message));
+                        }
+                        Self::InvalidField {
+                            field_name,
+                            message,
+                            ..
+                        } => {
+                            entries.push(entry(
+                                std::option::Option::Some(field_name.clone()),
+                                "invalid_field",
+                                message,
+                            ));
+                        }
+                        Self::ValidationFailed { message, fields, .. } if fields.is_empty() => {
+                            entries.push(entry(std::option::Option::None, "validation_failed", message));
+                        }
+                        Self::ValidationFailed { message, fields, .. } => {
+                            entries.extend(fields.iter().map(|field| {
+                                entry(
+                                    std::option::Option::Some(field.clone()),
+                                    "validation_failed",
+                                    message,
+                                )
+                            }));
+                        }
+                        Self::Multiple { errors } => {
+                            errors
+                                .iter()
+                                .for_each(|error| error.collect_report_entries(entries));
+                        }
+                    }
+                }
+            }
+
+            /// A flat summary of the builder's error, see `to_report()`.
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct #report_ident {
+                pub errors: std::vec::Vec<#report_entry_ident>,
+            }
+
+            /// A single failure within a report.
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct #report_entry_ident {
+                /// The field which failed, or `None` when the failure is not tied to one.
+                pub field: std::option::Option<std::borrow::Cow<'static, str>>,
+                /// One of `invalid_state`, `invalid_field`, or `validation_failed`.
+                pub kind: &'static str,
+                /// The error's `error_code()`.
+                pub code: &'static str,
+                pub message: std::borrow::Cow<'static, str>,
+            }
+
+            // the source is the cause of an error, not part of its identity
+            impl std::cmp::PartialEq for #builder_error_ident {
+                fn eq(&self, other: &Self) -> bool {
+                    match (self, other) {
+                        (
+                            Self::InvalidState { message, code },
+                            Self::InvalidState {
+                                message: other_message,
+                                code: other_code,
+                            },
+                        ) => message == other_message && code == other_code,
+                        (
+                            Self::InvalidField {
+                                field_name,
+                                message,
+                                location,
+                                code,
+                                ..
+                            },
+                            Self::InvalidField {
+                                field_name: other_field_name,
+                                message: other_message,
+                                location: other_location,
+                                code: other_code,
+                                ..
+                            },
+                        ) => {
+                            field_name == other_field_name
+                                && message == other_message
+                                && location == other_location
+                                && code == other_code
+                        }
+                        (
+                            Self::ValidationFailed {
+                                message,
+                                fields,
+                                code,
+                            },
+                            Self::ValidationFailed {
+                                message: other_message,
+                                fields: other_fields,
+                                code: other_code,
+                            },
+                        ) => message == other_message && fields == other_fields && code == other_code,
+                        (Self::Multiple { errors }, Self::Multiple { errors: other_errors }) => {
+                            errors == other_errors
+                        }
+                        _ => false,
+                    }
+                }
+            }
+
+            #display_and_error_impls
+        }
+    }
+
+    fn quote_builder_error_conversions(&self) -> proc_macro2::TokenStream {
+        let builder_error_ident = &self.builder_error_ident;
+
+        let conversions =
+            self.attributes
+                .get_error_into_targets()
+                .into_iter()
+                .map(|(target, with)| {
+                    // without a constructor the target is built from the message
+                    let conversion = match with {
+                        Some(path) => quote_spanned! {path.span()=>
+                            let constructor: fn(#builder_error_ident) -> #target = #path;
+
+                            constructor(error)
+                        },
+                        None => quote_spanned! {target.span()=>
+                            <Self as std::convert::From<std::string::String>>::from(
+                                std::string::ToString::to_string(&error),
+                            )
+                        },
+                    };
+
+                    quote! {
+                        impl std::convert::From<#builder_error_ident> for #target {
+                            fn from(error: #builder_error_ident) -> Self {
+                                #conversion
+                            }
+                        }
+                    }
+                });
+
+        quote! { #(#conversions)* }
+    }
+
+    fn quote_builder_error_serialize(&self) -> proc_macro2::TokenStream {
+        if !cfg!(feature = "serde") {
+            return quote! {};
+        }
+
+        let builder_error_ident = &self.builder_error_ident;
+        let builder_error_ident_string = builder_error_ident.to_string();
+        let report_ident = format_ident!("{}Report", builder_error_ident);
+        let report_ident_string = report_ident.to_string();
+        let report_entry_ident = format_ident!("{}ReportEntry", builder_error_ident);
+        let report_entry_ident_string = report_entry_ident.to_string();
 
         quote! {
-            #[derive(Clone, Debug, Default)]
-            pub struct #builder_ident {
-                #(#builder_fields)*
+            impl ::serde::Serialize for #builder_error_ident {
+                fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+                where
+                    S: ::serde::Serializer,
+                {
+                    use ::serde::ser::SerializeStructVariant;
+
+                    match self {
+                        #builder_error_ident::InvalidState { message, .. } => {
+                            let mut state = serializer.serialize_struct_variant(
+                                #builder_error_ident_string,
+                                0,
+                                "InvalidState",
+                                2,
+                            )?;
+                            state.serialize_field("message", message)?;
+                            state.serialize_field("code", self.error_code())?;
+                            state.end()
+                        }
+                        #builder_error_ident::InvalidField {
+                            field_name,
+                            message,
+                            ..
+                        } => {
+                            let mut state = serializer.serialize_struct_variant(
+                                #builder_error_ident_string,
+                                1,
+                                "InvalidField",
+                                3,
+                            )?;
+                            state.serialize_field("field_name", field_name)?;
+                            state.serialize_field("message", message)?;
+                            state.serialize_field("code", self.error_code())?;
+                            state.end()
+                        }
+                        #builder_error_ident::ValidationFailed {
+                            message,
+                            fields,
+                            ..
+                        } => {
+                            let mut state = serializer.serialize_struct_variant(
+                                #builder_error_ident_string,
+                                2,
+                                "ValidationFailed",
+                                3,
+                            )?;
+                            state.serialize_field("message", message)?;
+                            state.serialize_field("fields", fields)?;
+                            state.serialize_field("code", self.error_code())?;
+                            state.end()
+                        }
+                        #builder_error_ident::Multiple { errors } => {
+                            let mut state = serializer.serialize_struct_variant(
+                                #builder_error_ident_string,
+                                3,
+                                "Multiple",
+                                1,
+                            )?;
+                            state.serialize_field("errors", errors)?;
+                            state.end()
+                        }
+                    }
+                }
+            }
+
+            impl ::serde::Serialize for #report_ident {
+                fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+                where
+                    S: ::serde::Serializer,
+                {
+                    use ::serde::ser::SerializeStruct;
+
+                    let mut state = serializer.serialize_struct(#report_ident_string, 1)?;
+                    state.serialize_field("errors", &self.errors)?;
+                    state.end()
+                }
+            }
+
+            impl ::serde::Serialize for #report_entry_ident {
+                fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+                where
+                    S: ::serde::Serializer,
+                {
+                    use ::serde::ser::SerializeStruct;
+
+                    let mut state = serializer.serialize_struct(#report_entry_ident_string, 4)?;
+                    state.serialize_field("field", &self.field)?;
+                    state.serialize_field("kind", self.kind)?;
+                    state.serialize_field("code", self.code)?;
+                    state.serialize_field("message", &self.message)?;
+                    state.end()
+                }
             }
         }
     }
 
-    fn quote_builder_impl(&self) -> proc_macro2::TokenStream {
+    fn quote_builder_conversion(&self) -> proc_macro2::TokenStream {
         let struct_ident = &self.ident;
         let builder_ident = &self.builder_ident;
         let builder_error_ident = &self.builder_error_ident;
 
-        let field_setters = self
-            .fields
-            .iter()
-            .map(|field| field.quote_setter(&builder_error_ident));
+        let builder_param = match self.attributes.get_build_mode() {
+            BuildMode::Take => quote! { mut builder },
+            BuildMode::Ref | BuildMode::Owned => quote! { builder },
+        };
 
-        let missing_fields_ident = &self.missing_fields_ident;
+        let clone_bounds = self.quote_clone_bounds();
 
-        let missing_fields_validators =
-            self.fields.iter().map(TargetField::quote_missing_validator);
+        // `TryFrom` comes with `From`, and implementing both would conflict
+        if self.is_infallible() {
+            return quote! {
+                impl std::convert::From<#builder_ident> for #struct_ident #clone_bounds {
+                    fn from(#builder_param: #builder_ident) -> Self {
+                        builder.build()
+                    }
+                }
+            };
+        }
 
-        let result_fields = self.fields.iter().map(TargetField::quote_result_field);
+        quote! {
+            impl std::convert::TryFrom<#builder_ident> for #struct_ident #clone_bounds {
+                type Error = #builder_error_ident;
+
+                fn try_from(#builder_param: #builder_ident) -> std::result::Result<Self, Self::Error> {
+                    builder.build()
+                }
+            }
+        }
+    }
+
+    fn quote_struct_impl(&self) -> proc_macro2::TokenStream {
+        let struct_ident = &self.ident;
+        let builder_ident = &self.builder_ident;
+
+        // a `const fn` builder spells out its empty state, a field it cannot
+        // create is reported on the field
+        let (constness, empty_builder) = if self.attributes.is_const_fn()
+            && self.fields.iter().all(TargetField::is_const_creatable)
+        {
+            let unset_fields = self
+                .fields
+                .iter()
+                .map(TargetField::quote_const_unset_builder_field);
+            let deferred_errors_field = self
+                .attributes
+                .is_defer_errors()
+                .then(|| quote! { __deferred_errors: std::vec::Vec::new(), });
+            let checkpoints_field = self
+                .has_checkpoints()
+                .then(|| quote! { __checkpoints: std::vec::Vec::new(), });
+
+            (
+                quote! { const },
+                quote! {
+                    #builder_ident {
+                        #(#unset_fields)*
+                        #deferred_errors_field
+                        #checkpoints_field
+                    }
+                },
+            )
+        } else {
+            (quote! {}, quote! { #builder_ident::default() })
+        };
+
+        let builder_fn = if self.attributes.is_typestate() {
+            let typestate_ident = self.typestate_ident();
+            let (_, unset_ident) = self.typestate_marker_idents();
+            let unset_states = self
+                .fields
+                .iter()
+                .filter(|field| field.is_required())
+                .map(|_| &unset_ident);
 
-        let return_value = if let Some(validator_path) = self.attributes.get_validator_path() {
             quote! {
-                #validator_path(result)
+                pub #constness fn builder() -> #typestate_ident<#(#unset_states),*> {
+                    #typestate_ident {
+                        builder: #empty_builder,
+                        state: std::marker::PhantomData,
+                    }
+                }
             }
         } else {
             quote! {
-                Ok(result)
+                pub #constness fn builder() -> #builder_ident {
+                    #empty_builder
+                }
             }
         };
 
-        quote! {
-            impl #builder_ident {
-                #(#field_setters)*
+        let new_fn = self.quote_new_fn();
 
-                pub fn build(&self) -> std::result::Result<#struct_ident, #builder_error_ident> {
-                    let mut missing_fields = #missing_fields_ident::default();
+        let to_builder_fn = self.quote_to_builder_fn();
 
-                    #(#missing_fields_validators)*
+        let clone_bounds = self.quote_clone_bounds();
 
-                    missing_fields.as_builder_error()?;
+        quote! {
+            impl #struct_ident #clone_bounds {
+                #builder_fn
 
-                    let result = #struct_ident {
-                        #(#result_fields)*
-                    };
+                #new_fn
 
-                    #return_value
-                }
+                #to_builder_fn
             }
         }
     }
 
-    fn quote_missing_fields_block(&self) -> proc_macro2::TokenStream {
-        let missing_fields_ident = &self.missing_fields_ident;
-        let builder_error_ident = &self.builder_error_ident;
+    fn quote_target_default_impl(&self) -> proc_macro2::TokenStream {
+        if !self.attributes.is_impl_default() {
+            return quote! {};
+        }
 
-        quote! {
-            #[derive(Default)]
-            pub struct #missing_fields_ident(std::option::Option<Vec<&'static str>>);
+        let required_errors = self
+            .fields
+            .iter()
+            .filter(|field| field.is_required())
+            .map(|field| {
+                syn::Error::new(
+                    field.ident.span(),
+                    "`impl_default` requires every field to be an `Option`, use `each`, or have a `default`",
+                )
+                .to_compile_error()
+            })
+            .collect::<std::vec::Vec<_>>();
 
-            impl #missing_fields_ident {
-                fn add(&mut self, field: &'static str) -> &Self {
-                    self.0.get_or_insert_default().push(field);
-                    self
-                }
+        if !required_errors.is_empty() {
+            return quote! { #(#required_errors)* };
+        }
 
-                fn add_if_none<T>(&mut self, field_name: &'static str, field: &std::option::Option<T>) -> &mut Self {
-                    if field.is_none() {
-                        self.add(field_name);
-                    }
+        let struct_ident = &self.ident;
+        let builder_ident = &self.builder_ident;
+        let struct_ident_string = struct_ident.to_string();
 
-                    self
+        let mutability =
+            (self.attributes.get_build_mode() == BuildMode::Take).then(|| quote! { mut });
+
+        // validators still run, so a default which fails them is a bug
+        let build = if self.is_infallible() {
+            quote! { builder.build() }
+        } else {
+            quote! {
+                match builder.build() {
+                    std::result::Result::Ok(value) => value,
+                    std::result::Result::Err(err) => {
+                        panic!("the default `{}` is invalid: {err:#}", #struct_ident_string)
+                    }
                 }
+            }
+        };
 
-                fn as_builder_error(self) -> std::result::Result<(), #builder_error_ident> {
-                    let Some(missing_fields) = self.0 else {
-                        return Ok(());
-                    };
+        let clone_bounds = self.quote_clone_bounds();
+
+        quote! {
+            impl std::default::Default for #struct_ident #clone_bounds {
+                /// Builds the value from an empty builder.
+                fn default() -> Self {
+                    let #mutability builder = #builder_ident::default();
 
-                    Err(#builder_error_ident::missing_fields(&missing_fields))
+                    #build
                 }
             }
         }
     }
 
-    fn quote_builder_error_block(&self) -> proc_macro2::TokenStream {
+    fn quote_new_fn(&self) -> proc_macro2::TokenStream {
+        if !self.attributes.is_new() {
+            return quote! {};
+        }
+
+        let struct_ident = &self.ident;
+        let builder_ident = &self.builder_ident;
         let builder_error_ident = &self.builder_error_ident;
-        let struct_ident_string = self.ident.to_string();
+        let builder = format_ident!("__builder");
 
-        quote! {
-            /// Occurs when the user either tries to incorrectly assign a field,
-            /// or when they attempt to build the target struct while the builder
-            /// is in an invalid state.
-            #[derive(Clone, Debug, PartialEq)]
-            pub enum #builder_error_ident {
-                /// Typically occurs on the `build()` method. Examples include:
-                /// missing fields, constraint violations, and illogical structs.
-                InvalidState {
-                    message: std::borrow::Cow<'static, str>,
-                },
-                /// Typically occurs on the setter functions. Allows the builder
-                /// to catch problems before the user attempts to build the target.
-                InvalidField {
-                    field_name: std::borrow::Cow<'static, str>,
-                    message: std::borrow::Cow<'static, str>,
-                },
-            }
+        let required_fields = self.fields.iter().filter(|field| field.is_required());
+        let params = required_fields.clone().map(TargetField::quote_new_param);
+        let sets = required_fields
+            .clone()
+            .map(|field| field.quote_new_set(&builder));
 
-            impl #builder_error_ident {
-                pub fn missing_fields(fields: &[&str]) -> Self {
-                    let missing_field_names = fields
-                        .iter()
-                        .map(|field_name| format!("`{field_name}`"))
-                        .reduce(|acc, next| format!("{acc}, {next}"))
-                        .unwrap_or_default();
-                    Self::InvalidState {
-                        message: format!("missing required field(s): {missing_field_names}").into(),
+        let set_is_fallible = required_fields.clone().any(TargetField::returns_result);
+        let mutability = (required_fields.clone().next().is_some()
+            || self.attributes.get_build_mode() == BuildMode::Take)
+            .then(|| quote! { mut });
+        let build_is_fallible = self.fields.iter().any(TargetField::can_fail_build_when_set)
+            || self.has_struct_checks();
+
+        // with every required field given, only a check can still fail
+        if !set_is_fallible && !build_is_fallible {
+            let build = if self.is_infallible() {
+                quote! { #builder.build() }
+            } else {
+                quote! {
+                    match #builder.build() {
+                        std::result::Result::Ok(value) => value,
+                        std::result::Result::Err(_) => {
+                            unreachable!("every required field is set")
+                        }
                     }
                 }
+            };
 
-                pub fn missing_field(field: &str) -> Self {
-                    Self::missing_fields(&[field])
+            return quote! {
+                /// Creates the value from its required fields, the others
+                /// being unset or defaulted.
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(#(#params),*) -> #struct_ident {
+                    let #mutability #builder = #builder_ident::default();
+                    #(#sets)*
+
+                    #build
                 }
-            }
+            };
+        }
 
-            impl std::fmt::Display for #builder_error_ident {
-                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                    match self {
-                        #builder_error_ident::InvalidState { message } => {
-                            write!(f, "Unable to build {}: {}", #struct_ident_string, message)
-                        }
-                        #builder_error_ident::InvalidField {
-                            field_name,
-                            message,
-                        } => write!(f, "Unable to assign field `{field_name}`: {message}"),
-                    }
+        if set_is_fallible {
+            return quote! {
+                /// Creates a builder with the required fields set, for the
+                /// others to be set before building.
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    #(#params),*
+                ) -> std::result::Result<#builder_ident, #builder_error_ident> {
+                    let #mutability #builder = #builder_ident::default();
+                    #(#sets)*
+
+                    std::result::Result::Ok(#builder)
                 }
-            }
+            };
+        }
+
+        quote! {
+            /// Creates a builder with the required fields set, for the others
+            /// to be set before building.
+            #[allow(clippy::too_many_arguments)]
+            pub fn new(#(#params),*) -> #builder_ident {
+                let #mutability #builder = #builder_ident::default();
+                #(#sets)*
 
-            impl std::error::Error for #builder_error_ident {}
+                #builder
+            }
         }
     }
 
-    fn quote_struct_impl(&self) -> proc_macro2::TokenStream {
-        let struct_ident = &self.ident;
+    fn quote_to_builder_fn(&self) -> proc_macro2::TokenStream {
+        // a taking build exists so that field types need not be `Clone`
+        if self.attributes.get_build_mode() == BuildMode::Take {
+            return quote! {};
+        }
+
         let builder_ident = &self.builder_ident;
+        let seeded_fields = self
+            .fields
+            .iter()
+            .map(TargetField::quote_seeded_builder_field);
+
+        let deferred_errors_field = if self.attributes.is_defer_errors() {
+            quote! { __deferred_errors: std::vec::Vec::new(), }
+        } else {
+            quote! {}
+        };
+
+        let checkpoints_field = if self.has_checkpoints() {
+            quote! { __checkpoints: std::vec::Vec::new(), }
+        } else {
+            quote! {}
+        };
 
         quote! {
-            impl #struct_ident {
-                pub fn builder() -> #builder_ident {
-                    #builder_ident::default()
+            /// Creates a builder with every field set from this value, to
+            /// change some of them and build again.
+            pub fn to_builder(&self) -> #builder_ident {
+                #builder_ident {
+                    #(#seeded_fields)*
+                    #deferred_errors_field
+                    #checkpoints_field
                 }
             }
         }
@@ -206,32 +2641,64 @@ impl TryFrom<syn::DeriveInput> for TargetStruct {
     type Error = syn::Error;
 
     fn try_from(input: syn::DeriveInput) -> syn::Result<Self> {
-        let fields_named = extract_fields_named(&input)?;
+        let attributes: BuilderStructAttributes = input
+            .attrs
+            .iter()
+            .cloned()
+            .flat_map(BuilderStructAttributes::from)
+            .collect();
+
+        // report a malformed struct together with its attribute errors
+        let fields_named = extract_fields_named(&input).map_err(|mut err| {
+            attributes
+                .iter()
+                .filter_map(|attribute| attribute.as_ref().err())
+                .for_each(|attr_err| err.combine(attr_err.clone()));
+
+            err
+        })?;
 
         let struct_ident = &input.ident;
         let builder_ident = syn::Ident::new(&format!("{struct_ident}Builder"), struct_ident.span());
         let builder_error_ident =
             syn::Ident::new(&format!("{}Error", &builder_ident), struct_ident.span());
 
-        let fields = fields_named
-            .named
-            .iter()
-            .cloned()
-            .filter_map(|f| f.try_into().ok())
-            .collect();
-
         let missing_fields_ident = syn::Ident::new(
             &format!("Missing{}Fields", struct_ident),
             struct_ident.span(),
         );
 
-        let attributes = input
-            .attrs
+        let tristate_ident = format_ident!("{}Tristate", builder_ident);
+
+        let mut field_errors: std::option::Option<syn::Error> = None;
+        let fields = fields_named
+            .named
             .iter()
             .cloned()
-            .flat_map(BuilderStructAttributes::from)
+            .filter_map(|f| match TargetField::try_from(f) {
+                Ok(field) => Some(field),
+                Err(err) => {
+                    match &mut field_errors {
+                        Some(errors) => errors.combine(err),
+                        None => field_errors = Some(err),
+                    }
+
+                    None
+                }
+            })
+            .enumerate()
+            .map(|(index, field)| TargetField {
+                struct_attributes: attributes.clone(),
+                tristate_ident: tristate_ident.clone(),
+                index,
+                ..field
+            })
             .collect();
 
+        if let Some(errors) = field_errors {
+            return Err(errors);
+        }
+
         Ok(Self {
             ident: struct_ident.clone(),
             builder_ident,
@@ -245,25 +2712,167 @@ impl TryFrom<syn::DeriveInput> for TargetStruct {
 
 impl From<TargetStruct> for proc_macro2::TokenStream {
     fn from(value: TargetStruct) -> Self {
+        let struct_attr_errors = value
+            .attributes
+            .iter()
+            .filter_map(|attribute| attribute.as_ref().err())
+            .map(syn::Error::to_compile_error);
         let field_attr_errors = value.fields.iter().map(TargetField::quote_attr_errors);
+        let field_assertions = value.quote_field_assertions();
         let builder_struct = value.quote_builder_struct();
         let builder_impl = value.quote_builder_impl();
+        let typestate_builder = value.quote_typestate_builder();
         let missing_fields_block = value.quote_missing_fields_block();
         let builder_error_block = value.quote_builder_error_block();
+        let builder_error_conversions = value.quote_builder_error_conversions();
+        let builder_error_serialize = value.quote_builder_error_serialize();
         let struct_impl = value.quote_struct_impl();
+        let builder_conversion = value.quote_builder_conversion();
+        let target_default_impl = value.quote_target_default_impl();
+
+        let expansion = quote! {
+            #(#struct_attr_errors)*
 
-        quote! {
             #(#field_attr_errors)*
 
+            #field_assertions
+
             #builder_struct
 
             #builder_impl
 
+            #typestate_builder
+
             #missing_fields_block
 
             #builder_error_block
 
+            #builder_error_conversions
+
+            #builder_error_serialize
+
             #struct_impl
+
+            #builder_conversion
+
+            #target_default_impl
+        };
+
+        if !value.attributes.is_debug_expansion() {
+            return expansion;
+        }
+
+        let location = expansion_location(&value.ident);
+
+        match write_expansion(&expansions_dir(), &value.ident, &location, &expansion) {
+            Ok(_) => expansion,
+            Err(err) => {
+                let error = syn::Error::new(
+                    value.ident.span(),
+                    format!("unable to write the expansion for `debug_expansion`: {err}"),
+                )
+                .to_compile_error();
+
+                quote! {
+                    #error
+
+                    #expansion
+                }
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _write_expansion_per_declaration() {
+        let dir = std::env::temp_dir().join(format!("turann-expansions-{}", std::process::id()));
+        let struct_ident: syn::Ident = syn::parse_quote!(Command);
+        let expansion = quote! { impl Command { pub fn builder() -> CommandBuilder { CommandBuilder::default() } } };
+
+        let path = write_expansion(&dir, &struct_ident, "src/lib.rs:3:12", &expansion).unwrap();
+        let other_path =
+            write_expansion(&dir, &struct_ident, "src/jobs.rs:3:12", &expansion).unwrap();
+
+        let file_name = path.file_name().unwrap().to_str().unwrap();
+        assert!(file_name.starts_with("Command-") && file_name.ends_with(".rs"));
+        assert_ne!(path, other_path);
+
+        let expected = if cfg!(feature = "debug-expansion") {
+            "impl Command {\n    pub fn builder() -> CommandBuilder {\n        CommandBuilder::default()\n    }\n}\n"
+                .to_owned()
+        } else {
+            expansion.to_string()
+        };
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), expected);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn _name_typestate_parameters_after_their_fields() {
+        let names = [
+            syn::parse_quote!(port),
+            syn::parse_quote!(max_workers),
+            syn::parse_quote!(r#type),
+        ]
+        .iter()
+        .map(upper_camel_case)
+        .collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["Port", "MaxWorkers", "Type"]);
+    }
+
+    #[test]
+    fn _report_every_field_which_fails_to_parse() {
+        let mut input: syn::DeriveInput = syn::parse_quote! {
+            struct Command {
+                executable: String,
+                args: Vec<String>,
+                current_dir: String,
+            }
+        };
+
+        let syn::Data::Struct(data) = &mut input.data else {
+            unreachable!()
+        };
+        data.fields
+            .iter_mut()
+            .filter(|field| field.ident.as_ref().is_some_and(|ident| ident != "args"))
+            .for_each(|field| field.ident = None);
+
+        let errors = TargetStruct::try_from(input).unwrap_err();
+
+        assert_eq!(
+            errors
+                .into_iter()
+                .map(|err| err.to_string())
+                .collect::<Vec<_>>(),
+            vec!["Unable to find field ident", "Unable to find field ident"]
+        );
+    }
+
+    #[test]
+    fn _report_struct_attribute_errors_with_an_unsupported_shape() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[builder(unknown_option)]
+            struct Command(String);
+        };
+
+        let errors = TargetStruct::try_from(input).unwrap_err();
+
+        assert_eq!(
+            errors
+                .into_iter()
+                .map(|err| err.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "Cannot create Builder for tuple structs",
+                "builder struct attribute not recognized"
+            ]
+        );
+    }
+}
@@ -25,4 +25,10 @@ pub struct Command {
     current_dir: Option<String>,
 }
 
+#[derive(Builder)]
+#[builder(valdate = Self::check)]
+pub struct Job {
+    name: String,
+}
+
 fn main() {}
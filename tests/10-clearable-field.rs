@@ -0,0 +1,34 @@
+// Builders that get reused need a way to revert a single field without
+// throwing the whole builder away.
+//
+// Fields marked with #[builder(clearable)] get a `clear_*` method which puts
+// the field back into its unset state. Collections are emptied.
+
+use turann::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(clearable)]
+    executable: String,
+    #[builder(each = "arg", clearable)]
+    args: Vec<String>,
+    #[builder(clearable)]
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .current_dir("..".to_owned());
+
+    builder.clear_args().clear_current_dir();
+
+    let command = builder.build().unwrap();
+    assert!(command.args.is_empty());
+    assert!(command.current_dir.is_none());
+
+    builder.clear_executable();
+    assert!(builder.build().is_err());
+}
@@ -0,0 +1,46 @@
+// An `each` method on a map replaces the value of a key which was added
+// before. With #[builder(dedup)] it keeps the first value instead, and with
+// `dedup = "error"` it returns a `Result` and rejects the second entry, which
+// protects against conflicting configuration keys.
+
+use std::collections::{BTreeMap, HashMap};
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Config {
+    #[builder(each = "header")]
+    headers: HashMap<String, String>,
+    #[builder(each = "default_header", dedup)]
+    default_headers: HashMap<String, String>,
+    #[builder(each = "setting", dedup = "error")]
+    settings: BTreeMap<String, u32>,
+}
+
+fn main() {
+    let mut builder = Config::builder();
+    builder
+        .header("accept", "text/html")
+        .header("accept", "application/json")
+        .default_header("user-agent", "turann")
+        .default_header("user-agent", "curl");
+    builder.setting("retries", 3u32).unwrap();
+
+    let err = builder.setting("retries", 5u32).unwrap_err();
+    match err {
+        ConfigBuilderError::InvalidField {
+            ref field_name,
+            code,
+            ..
+        } => {
+            assert_eq!(field_name, "settings");
+            assert_eq!(code, Some("duplicate"));
+        }
+        err => panic!("unexpected error: {err}"),
+    }
+
+    let config = builder.build();
+    assert_eq!(config.headers["accept"], "application/json");
+    assert_eq!(config.default_headers["user-agent"], "turann");
+    assert_eq!(config.settings["retries"], 3);
+}
@@ -0,0 +1,28 @@
+// With the `smallvec` feature, `each` also works on a `SmallVec<[T; N]>`,
+// which keeps its first `N` items inline rather than on the heap.
+
+use smallvec::SmallVec;
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Route {
+    path: String,
+    #[builder(each = "method")]
+    methods: SmallVec<[String; 2]>,
+    #[builder(each = "segment", capacity = 8)]
+    segments: SmallVec<[u16; 4]>,
+}
+
+fn main() {
+    let route = Route::builder()
+        .path("/items")
+        .method("GET")
+        .method("HEAD")
+        .segments([1u16, 2, 3])
+        .build()
+        .unwrap();
+
+    assert_eq!(route.methods.as_slice(), ["GET", "HEAD"]);
+    assert!(!route.methods.spilled());
+    assert_eq!(route.segments.as_slice(), [1, 2, 3]);
+}
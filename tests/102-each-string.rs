@@ -0,0 +1,38 @@
+// `each` also works on a `String` field, appending every item to it. This
+// suits builders which assemble a script, a query, or a longer description
+// piece by piece. With `separator` the items are joined by it.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Script {
+    #[builder(each = "line", separator = "\n")]
+    body: String,
+    #[builder(each = "word", separator = " ")]
+    description: Option<String>,
+    #[builder(each = "chunk")]
+    raw: String,
+}
+
+fn main() {
+    let script = Script::builder()
+        .line("set -e")
+        .line("cargo build")
+        .body(["cargo test", "cargo doc"])
+        .chunk("ab")
+        .chunk("cd")
+        .build();
+
+    assert_eq!(script.body, "set -e\ncargo build\ncargo test\ncargo doc");
+    assert_eq!(script.description, None);
+    assert_eq!(script.raw, "abcd");
+
+    let script = Script::builder()
+        .word("builds")
+        .word("the")
+        .word("crate")
+        .build();
+
+    assert_eq!(script.description.as_deref(), Some("builds the crate"));
+    assert!(script.body.is_empty());
+}
@@ -0,0 +1,42 @@
+// `each` on a `Vec<Vec<T>>` adds a whole group at a time, taking any iterator
+// over items which convert into `T`. With #[builder(group)] there are also
+// `new_<each>` to start a new group and `<each>_item` to add a single item to
+// the last one, which suits items that arrive one by one.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Table {
+    #[builder(each = "row")]
+    rows: Vec<Vec<String>>,
+    #[builder(each = "batch", group)]
+    batches: Vec<Vec<u64>>,
+}
+
+fn main() {
+    let table = Table::builder()
+        .row(["id", "name"])
+        .row(vec![String::from("1"), String::from("turann")])
+        .rows([["2", "builder"]])
+        .batch_item(1u32)
+        .batch_item(2u32)
+        .new_batch()
+        .batch_item(3u32)
+        .batch([4u32, 5])
+        .build();
+
+    assert_eq!(
+        table.rows,
+        vec![
+            vec!["id", "name"],
+            vec!["1", "turann"],
+            vec!["2", "builder"],
+        ]
+    );
+    assert_eq!(table.batches, vec![vec![1, 2], vec![3], vec![4, 5]]);
+
+    let table = Table::builder().new_batch().batch_item(1u64).build();
+
+    assert!(table.rows.is_empty());
+    assert_eq!(table.batches, vec![vec![1]]);
+}
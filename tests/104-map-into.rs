@@ -0,0 +1,40 @@
+// The key and the value of a map's `each` method both take `impl Into`, so
+// `header("Accept", "application/json")` needs no `.to_string()` on either
+// side. The same goes for the pairs of the bulk method and for keys and
+// values which are not strings.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Request {
+    #[builder(each = "header")]
+    headers: HashMap<String, String>,
+    #[builder(each = "mount")]
+    mounts: BTreeMap<PathBuf, u64>,
+}
+
+fn main() {
+    let request = Request::builder()
+        .header("Accept", "application/json")
+        .header(String::from("Host"), "example.com")
+        .headers([("User-Agent", "turann")])
+        .mount("/data", 1u32)
+        .mount(PathBuf::from("/logs"), 2u64)
+        .mounts([("/tmp", 3u8)])
+        .build();
+
+    assert_eq!(request.headers["Accept"], "application/json");
+    assert_eq!(request.headers["Host"], "example.com");
+    assert_eq!(request.headers["User-Agent"], "turann");
+    assert_eq!(
+        request.mounts.into_iter().collect::<Vec<_>>(),
+        vec![
+            (PathBuf::from("/data"), 1),
+            (PathBuf::from("/logs"), 2),
+            (PathBuf::from("/tmp"), 3),
+        ],
+    );
+}
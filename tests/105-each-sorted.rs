@@ -0,0 +1,31 @@
+// With #[builder(sorted)] an `each` method on a `Vec` inserts every item at
+// its place, found by a binary search, so the built `Vec` is sorted however
+// the items were added. Together with `dedup` the binary search also finds
+// the items it already holds.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Schedule {
+    #[builder(each = "slot", sorted)]
+    slots: Vec<u32>,
+    #[builder(each = "tag", sorted, dedup)]
+    tags: Vec<String>,
+}
+
+fn main() {
+    let schedule = Schedule::builder()
+        .slot(30u32)
+        .slot(10u32)
+        .slot(20u32)
+        .slot(10u32)
+        .slots([5u32, 25])
+        .tag("nightly")
+        .tag("backup")
+        .tag("nightly")
+        .tag("cleanup")
+        .build();
+
+    assert_eq!(schedule.slots, vec![5, 10, 10, 20, 25, 30]);
+    assert_eq!(schedule.tags, vec!["backup", "cleanup", "nightly"]);
+}
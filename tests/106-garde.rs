@@ -0,0 +1,53 @@
+// With #[builder(use_garde)], `build()` runs the checks of a struct deriving
+// `garde::Validate`. Every field in the report becomes its own `InvalidField`
+// error, named by its path, and several of them are reported together. The
+// caller's crate needs to depend on `garde`.
+
+use garde::Validate;
+use turann::Builder;
+
+#[derive(Debug, Builder, Validate)]
+#[builder(use_garde)]
+pub struct Account {
+    #[garde(length(min = 3, max = 16))]
+    name: String,
+    #[garde(range(min = 18))]
+    age: u32,
+}
+
+fn main() {
+    let account = Account::builder()
+        .name("ferris")
+        .age(21_u32)
+        .build()
+        .unwrap();
+    assert_eq!(account.name, "ferris");
+
+    let err = Account::builder()
+        .name("ab")
+        .age(21_u32)
+        .build()
+        .unwrap_err();
+    assert!(matches!(
+        &err,
+        AccountBuilderError::InvalidField { field_name, .. } if field_name == "name"
+    ));
+
+    let err = Account::builder()
+        .name("ab")
+        .age(3_u32)
+        .build()
+        .unwrap_err();
+    let AccountBuilderError::Multiple { errors } = err else {
+        panic!("expected both fields to be reported, got {err:?}");
+    };
+    let mut field_names = errors
+        .iter()
+        .map(|err| match err {
+            AccountBuilderError::InvalidField { field_name, .. } => field_name.to_string(),
+            err => panic!("expected an invalid field, got {err:?}"),
+        })
+        .collect::<Vec<_>>();
+    field_names.sort();
+    assert_eq!(field_names, ["age", "name"]);
+}
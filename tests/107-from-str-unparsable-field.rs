@@ -0,0 +1,17 @@
+// A field whose type has no `FromStr` impl cannot be set by `set_from_str`.
+// The error points at the field's type, which can be opted out of with
+// #[builder(skip_from_str)].
+
+use turann::Builder;
+
+#[derive(Clone, Debug)]
+pub struct Proxy;
+
+#[derive(Debug, Builder)]
+#[builder(from_str)]
+pub struct Server {
+    host: String,
+    proxy: Option<Proxy>,
+}
+
+fn main() {}
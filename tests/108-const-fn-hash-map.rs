@@ -0,0 +1,19 @@
+// With #[builder(const_fn)], `builder()` is a `const fn` which creates the
+// collections of `each` fields. A `HashMap` or `HashSet` cannot be created in
+// a `const fn`, so the field is rejected.
+
+use std::collections::{HashMap, HashSet};
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(const_fn)]
+pub struct Limits {
+    max_connections: u32,
+    #[builder(each = "header")]
+    headers: HashMap<String, String>,
+    #[builder(each = "host")]
+    hosts: HashSet<String>,
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+// With the `arrayvec` feature, `each` also works on an `ArrayVec<T, N>`, which
+// holds at most `N` items inline. The `each` method returns a `Result` and
+// rejects an item once the collection is full.
+
+use arrayvec::ArrayVec;
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Route {
+    path: String,
+    #[builder(each = "method")]
+    methods: ArrayVec<String, 2>,
+}
+
+fn main() {
+    let mut builder = Route::builder();
+    builder
+        .path("/items")
+        .method("GET")
+        .unwrap()
+        .method("HEAD")
+        .unwrap();
+
+    let err = builder.method("POST").unwrap_err();
+    assert_eq!(err.error_code(), "capacity_exceeded");
+    assert!(matches!(
+        &err,
+        RouteBuilderError::InvalidField { field_name, message, .. }
+            if field_name == "methods[2]" && message == "capacity exceeded"
+    ));
+
+    // the rejected item is not added
+    let route = builder.build().unwrap();
+    assert_eq!(route.methods.as_slice(), ["GET", "HEAD"]);
+}
@@ -0,0 +1,36 @@
+// Calling code sometimes needs to look at what has been set on a builder so
+// far without building it.
+//
+// Every field gets a `get_*` accessor returning `Option<&T>`, where `None`
+// means the field has not been set yet. Optional fields are unwrapped one
+// level, so `Option<String>` is read back as `Option<&String>`. Collections
+// built with `each` read back as `None` until they hold an element, so
+// `get_*` returns `None` exactly when `has_*` is false.
+
+use turann::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    assert_eq!(builder.get_executable(), None);
+    assert_eq!(builder.get_args(), None);
+    assert!(!builder.has_args());
+    assert_eq!(builder.get_current_dir(), None);
+
+    builder
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .current_dir("..".to_owned());
+
+    assert_eq!(builder.get_executable().map(String::as_str), Some("cargo"));
+    assert_eq!(builder.get_args().map(Vec::len), Some(1));
+    assert!(builder.has_args());
+    assert_eq!(builder.get_current_dir().map(String::as_str), Some(".."));
+}
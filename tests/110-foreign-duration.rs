@@ -0,0 +1,27 @@
+// With the `duration` feature, only `std::time::Duration` fields get the
+// `*_secs` and `*_millis` setters. The `Duration` of another crate, like
+// `chrono::Duration`, keeps its regular setter only.
+
+use std::time::Duration;
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Job {
+    timeout: Duration,
+    delay: chrono::Duration,
+    retry_after: Option<chrono::Duration>,
+}
+
+fn main() {
+    let job = Job::builder()
+        .timeout_secs(30)
+        .delay(chrono::Duration::seconds(5))
+        .retry_after(chrono::Duration::minutes(1))
+        .build()
+        .unwrap();
+
+    assert_eq!(job.timeout, Duration::from_secs(30));
+    assert_eq!(job.delay, chrono::Duration::seconds(5));
+    assert_eq!(job.retry_after, Some(chrono::Duration::minutes(1)));
+}
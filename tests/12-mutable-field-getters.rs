@@ -0,0 +1,34 @@
+// Alongside the getters, every field gets a `get_*_mut` accessor so values
+// already stored in the builder can be edited in place instead of being
+// replaced wholesale.
+
+use turann::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    assert!(builder.get_executable_mut().is_none());
+    assert!(builder.get_args_mut().is_none());
+
+    builder.executable("cargo".to_owned()).arg("build".to_owned());
+
+    if let Some(executable) = builder.get_executable_mut() {
+        executable.push_str("-nightly");
+    }
+
+    if let Some(args) = builder.get_args_mut() {
+        args.push("--release".to_owned());
+    }
+
+    let command = builder.build().unwrap();
+    assert_eq!(command.executable, "cargo-nightly");
+    assert_eq!(command.args, vec!["build", "--release"]);
+    assert!(command.current_dir.is_none());
+}
@@ -0,0 +1,28 @@
+// Code that drives a builder dynamically (config layering, CLI merging) needs
+// to know which fields still need a value before calling `build()`.
+//
+// Every field gets a `has_*` predicate. Collections count as set once they
+// contain at least one element.
+
+use turann::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    assert!(!builder.has_executable());
+    assert!(!builder.has_args());
+    assert!(!builder.has_current_dir());
+
+    builder.executable("cargo".to_owned()).arg("build".to_owned());
+
+    assert!(builder.has_executable());
+    assert!(builder.has_args());
+    assert!(!builder.has_current_dir());
+}
@@ -0,0 +1,60 @@
+// Plugin and scripting hosts often only know field names at runtime.
+//
+// The builder gets a `set_any` method taking a field name and a boxed value.
+// The value is downcast to the setter's argument type and forwarded to the
+// regular setter, so validators still run. Unknown field names and values of
+// the wrong type are reported as `InvalidField` errors.
+
+use std::any::Any;
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(validate = Self::nonzero)]
+    jobs: usize,
+}
+
+impl CommandBuilder {
+    fn nonzero(value: usize) -> Result<usize, CommandBuilderError> {
+        if value == 0 {
+            return Err(CommandBuilderError::invalid_field("jobs", "must not be zero"));
+        }
+
+        Ok(value)
+    }
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder
+        .set_any("executable", Box::new("cargo".to_owned()))
+        .unwrap()
+        .set_any("args", Box::new("build".to_owned()))
+        .unwrap()
+        .set_any("current_dir", Box::new("..".to_owned()))
+        .unwrap()
+        .set_any("jobs", Box::new(4_usize))
+        .unwrap();
+
+    let command = builder.build().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+    assert_eq!(command.jobs, 4);
+
+    let unknown = builder.set_any("nope", Box::new(())).unwrap_err();
+    assert!(matches!(
+        unknown,
+        CommandBuilderError::InvalidField { ref field_name, .. } if field_name == "nope"
+    ));
+
+    let wrong_type: Box<dyn Any> = Box::new(42_u8);
+    assert!(builder.set_any("executable", wrong_type).is_err());
+
+    assert!(builder.set_any("jobs", Box::new(0_usize)).is_err());
+}
@@ -0,0 +1,60 @@
+// Config files and `key=value` pairs deliver every value as a string.
+//
+// With #[builder(from_str)] on the struct, the builder gets a `set_from_str`
+// method which parses the raw string with the field type's `FromStr` impl and
+// forwards the result to the regular setter. Parse failures and unknown
+// fields are reported as `InvalidField` errors.
+//
+// Collections other than an `each` field cannot be parsed from a string, and
+// neither can fields marked #[builder(skip_from_str)]. Setting them from a
+// string is an `InvalidField` error as well.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(from_str)]
+pub struct Server {
+    host: String,
+    port: u16,
+    #[builder(each = "alias")]
+    aliases: Vec<String>,
+    verbose: Option<bool>,
+    tags: Vec<String>,
+    #[builder(skip_from_str)]
+    proxy: Option<Proxy>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Proxy;
+
+fn main() {
+    let mut builder = Server::builder();
+
+    for (key, value) in [
+        ("host", "localhost"),
+        ("port", "8080"),
+        ("aliases", "example.com"),
+        ("verbose", "true"),
+    ] {
+        builder.set_from_str(key, value).unwrap();
+    }
+
+    let server = builder.build().unwrap();
+    assert_eq!(server.host, "localhost");
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.aliases, vec!["example.com"]);
+    assert_eq!(server.verbose, Some(true));
+
+    let err = builder.set_from_str("port", "eighty").unwrap_err();
+    assert!(matches!(
+        err,
+        ServerBuilderError::InvalidField { ref field_name, .. } if field_name == "port"
+    ));
+
+    assert!(builder.set_from_str("nope", "").is_err());
+
+    for field in ["tags", "proxy"] {
+        let err = builder.set_from_str(field, "x").unwrap_err();
+        assert!(err.to_string().contains("cannot be set from a string"));
+    }
+}
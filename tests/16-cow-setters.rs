@@ -0,0 +1,35 @@
+// Fields holding `Cow<'static, str>` accept both `&'static str` (stored as
+// `Cow::Borrowed`) and `String` (stored as `Cow::Owned`) through the regular
+// setter.
+//
+// Borrowed strings that don't live for `'static` can't go through that setter,
+// so an additional `*_owned` setter takes anything convertible into a `String`
+// and always stores it as `Cow::Owned`.
+
+use std::borrow::Cow;
+
+use turann::Builder;
+
+#[derive(Builder)]
+pub struct Greeting {
+    greeting: Cow<'static, str>,
+    name: Option<Cow<'static, str>>,
+    #[builder(each = "tag")]
+    tags: Vec<Cow<'static, str>>,
+}
+
+fn main() {
+    let local = String::from("world");
+
+    let greeting = Greeting::builder()
+        .greeting("hello")
+        .name_owned(&local)
+        .tag(String::from("owned"))
+        .tag_owned(local.as_str())
+        .build()
+        .unwrap();
+
+    assert!(matches!(greeting.greeting, Cow::Borrowed("hello")));
+    assert!(matches!(greeting.name, Some(Cow::Owned(ref name)) if name == "world"));
+    assert!(greeting.tags.iter().all(|tag| matches!(tag, Cow::Owned(_))));
+}
@@ -0,0 +1,42 @@
+// Fields holding `Arc<T>`, `Rc<T>`, or `Box<T>` can be marked with
+// #[builder(wrap)] so their setter accepts the pointee and wraps it.
+//
+// Values that are already wrapped can still be passed in through a secondary
+// setter: `*_shared` for `Arc`/`Rc` and `*_boxed` for `Box`.
+
+use std::{rc::Rc, sync::Arc};
+
+use turann::Builder;
+
+#[derive(Builder)]
+pub struct Service {
+    #[builder(wrap)]
+    name: Arc<String>,
+    #[builder(wrap)]
+    description: Option<Rc<String>>,
+    #[builder(wrap)]
+    payload: Box<Vec<u8>>,
+}
+
+fn main() {
+    let shared = Arc::new(String::from("shared"));
+
+    let service = Service::builder()
+        .name("service")
+        .description(String::from("description"))
+        .payload(vec![1, 2, 3])
+        .build()
+        .unwrap();
+
+    assert_eq!(*service.name, "service");
+    assert_eq!(service.description.as_deref().map(String::as_str), Some("description"));
+    assert_eq!(*service.payload, vec![1, 2, 3]);
+
+    let service = Service::builder()
+        .name_shared(shared.clone())
+        .payload_boxed(Box::new(vec![]))
+        .build()
+        .unwrap();
+
+    assert!(Arc::ptr_eq(&service.name, &shared));
+}
@@ -0,0 +1,42 @@
+// With the `duration` feature enabled, `Duration` fields get `*_secs` and
+// `*_millis` setters. The `humantime` feature additionally generates a `*_str`
+// setter parsing strings like "30s" or "1h 15m".
+
+use std::time::Duration;
+
+use turann::Builder;
+
+#[derive(Builder)]
+pub struct Client {
+    timeout: Duration,
+    keep_alive: Option<Duration>,
+    #[builder(each = "backoff")]
+    backoffs: Vec<Duration>,
+}
+
+fn main() {
+    let client = Client::builder()
+        .timeout_secs(30)
+        .keep_alive_millis(1500)
+        .backoff_millis(100)
+        .backoff_secs(1)
+        .build()
+        .unwrap();
+
+    assert_eq!(client.timeout, Duration::from_secs(30));
+    assert_eq!(client.keep_alive, Some(Duration::from_millis(1500)));
+    assert_eq!(client.backoffs, vec![Duration::from_millis(100), Duration::from_secs(1)]);
+
+    #[cfg(feature = "humantime")]
+    {
+        let client = Client::builder()
+            .timeout_str("1m 30s")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(client.timeout, Duration::from_secs(90));
+
+        assert!(Client::builder().timeout_str("soon").is_err());
+    }
+}
@@ -0,0 +1,29 @@
+// With the `chrono` feature enabled, `DateTime<Tz>` fields get an `*_rfc3339`
+// setter which parses the string and converts it into the field's timezone.
+
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use turann::Builder;
+
+#[derive(Builder)]
+pub struct Event {
+    starts_at: DateTime<Utc>,
+    ends_at: Option<DateTime<FixedOffset>>,
+}
+
+fn main() {
+    let event = Event::builder()
+        .starts_at_rfc3339("2024-05-01T12:00:00+02:00")
+        .unwrap()
+        .ends_at_rfc3339("2024-05-01T14:00:00+02:00")
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        event.starts_at,
+        Utc.with_ymd_and_hms(2024, 5, 1, 10, 0, 0).unwrap()
+    );
+    assert_eq!(event.ends_at.unwrap().offset().local_minus_utc(), 2 * 60 * 60);
+
+    assert!(Event::builder().starts_at_rfc3339("yesterday").is_err());
+}
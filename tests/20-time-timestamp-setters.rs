@@ -0,0 +1,23 @@
+// With the `time` feature enabled, `OffsetDateTime` fields get an `*_rfc3339`
+// setter which parses the string with `time`'s well-known RFC 3339 format.
+
+use time::{OffsetDateTime, UtcOffset};
+use turann::Builder;
+
+#[derive(Builder)]
+pub struct Event {
+    starts_at: OffsetDateTime,
+}
+
+fn main() {
+    let event = Event::builder()
+        .starts_at_rfc3339("2024-05-01T12:00:00+02:00")
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(event.starts_at.unix_timestamp(), 1_714_557_600);
+    assert_eq!(event.starts_at.offset(), UtcOffset::from_hms(2, 0, 0).unwrap());
+
+    assert!(Event::builder().starts_at_rfc3339("yesterday").is_err());
+}
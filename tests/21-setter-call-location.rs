@@ -0,0 +1,48 @@
+// Validated setters are #[track_caller], and the `InvalidField` errors they
+// return record where the setter was called. When a long chain fails, the
+// error points at the offending call rather than at the derive. The alternate
+// formatter (`{:#}`) appends that location to the message.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Server {
+    #[builder(validate = Self::nonzero)]
+    port: u16,
+    #[builder(validate = Self::nonzero)]
+    backlog: Option<u16>,
+}
+
+impl ServerBuilder {
+    fn nonzero(value: u16) -> Result<u16, ServerBuilderError> {
+        if value == 0 {
+            return Err(ServerBuilderError::invalid_field("value", "must not be zero"));
+        }
+
+        Ok(value)
+    }
+}
+
+fn main() {
+    let mut builder = Server::builder();
+
+    let expected_line = line!() + 1;
+    let err = builder.port(80_u16).unwrap().backlog(0_u16).unwrap_err();
+
+    assert_eq!(err.to_string(), "Unable to assign field `value`: must not be zero");
+    assert!(
+        format!("{err:#}").starts_with("Unable to assign field `value`: must not be zero (at ")
+    );
+
+    let ServerBuilderError::InvalidField {
+        location: Some(location),
+        ..
+    } = &err
+    else {
+        panic!("expected an InvalidField error with a location, got {err:?}");
+    };
+
+    assert!(location.file().ends_with("21-setter-call-location.rs"));
+    assert_eq!(location.line(), expected_line);
+    assert!(format!("{err:#}").ends_with(&format!("(at {location})")));
+}
@@ -0,0 +1,111 @@
+// With #[builder(tracing)] on the struct, setters emit a `tracing::trace!`
+// event naming the field being set and `build()` runs inside a trace span.
+// The caller's crate needs to depend on `tracing`.
+//
+// A small subscriber records what is emitted, so the test can check the
+// names and fields of the span and of the events.
+
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use turann::Builder;
+
+#[derive(Builder)]
+#[builder(tracing)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+#[derive(Default)]
+struct Fields(Vec<String>);
+
+impl Visit for Fields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push(format!("{}={value}", field.name()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.push(format!("{}={value:?}", field.name()));
+    }
+}
+
+#[derive(Clone, Default)]
+struct Recorder {
+    records: Arc<Mutex<Vec<String>>>,
+}
+
+impl Recorder {
+    fn push(&self, record: String) {
+        self.records.lock().unwrap().push(record);
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields::default();
+        span.record(&mut fields);
+        self.push(format!(
+            "span {} {}",
+            span.metadata().name(),
+            fields.0.join(" ")
+        ));
+
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        self.push(format!(
+            "event {} {}",
+            event.metadata().level(),
+            fields.0.join(" ")
+        ));
+    }
+
+    fn enter(&self, _: &Id) {
+        self.push("enter".to_owned());
+    }
+
+    fn exit(&self, _: &Id) {
+        self.push("exit".to_owned());
+    }
+}
+
+fn main() {
+    let recorder = Recorder::default();
+
+    let command = tracing::subscriber::with_default(recorder.clone(), || {
+        Command::builder()
+            .executable("cargo".to_owned())
+            .arg("build".to_owned())
+            .build()
+            .unwrap()
+    });
+
+    assert_eq!(command.executable, "cargo");
+    assert!(command.current_dir.is_none());
+
+    assert_eq!(
+        *recorder.records.lock().unwrap(),
+        [
+            "event TRACE message=setting builder field field=executable",
+            "event TRACE message=setting builder field field=args",
+            "span build target=Command",
+            "enter",
+            "exit",
+        ]
+    );
+}
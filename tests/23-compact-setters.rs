@@ -0,0 +1,35 @@
+// Crates with hundreds of derived builders spend a lot of compile time
+// monomorphizing `impl Into<T>` setters.
+//
+// With #[builder(compact)] on the struct, setters take the field type directly
+// and are marked #[inline(never)], so each setter is compiled exactly once.
+
+use std::sync::Arc;
+
+use turann::Builder;
+
+#[derive(Builder)]
+#[builder(compact)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(wrap)]
+    label: Arc<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .current_dir("..".to_owned())
+        .label("release".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+    assert_eq!(*command.label, "release");
+}
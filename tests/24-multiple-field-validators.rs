@@ -0,0 +1,55 @@
+// A field may declare several validators. They run in declaration order, each
+// receiving the output of the previous one, and the first failure stops the
+// setter.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Account {
+    #[builder(validate = Self::trimmed, validate = Self::non_empty)]
+    #[builder(validate = Self::short)]
+    username: String,
+}
+
+impl AccountBuilder {
+    fn trimmed(value: String) -> Result<String, AccountBuilderError> {
+        Ok(value.trim().to_owned())
+    }
+
+    fn non_empty(value: String) -> Result<String, AccountBuilderError> {
+        if value.is_empty() {
+            return Err(AccountBuilderError::invalid_field("username", "cannot be empty"));
+        }
+
+        Ok(value)
+    }
+
+    fn short(value: String) -> Result<String, AccountBuilderError> {
+        if value.len() > 8 {
+            return Err(AccountBuilderError::invalid_field("username", "too long"));
+        }
+
+        Ok(value)
+    }
+}
+
+fn main() {
+    let account = Account::builder()
+        .username("  ferris  ")
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(account.username, "ferris");
+
+    let err = Account::builder().username("   ").unwrap_err();
+    assert!(matches!(
+        err,
+        AccountBuilderError::InvalidField { ref message, .. } if message == "cannot be empty"
+    ));
+
+    let err = Account::builder().username("  a very long name ").unwrap_err();
+    assert!(matches!(
+        err,
+        AccountBuilderError::InvalidField { ref message, .. } if message == "too long"
+    ));
+}
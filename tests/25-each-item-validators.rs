@@ -0,0 +1,35 @@
+// Collections built up one element at a time can validate every pushed element
+// with #[builder(validate_item = path)]. The `each` method then returns a
+// `Result`, and the error names the index of the element that failed.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Post {
+    #[builder(each = "tag", validate_item = Self::lowercase)]
+    tags: Vec<String>,
+}
+
+impl PostBuilder {
+    fn lowercase(value: String) -> Result<String, PostBuilderError> {
+        if value.chars().any(char::is_uppercase) {
+            return Err(PostBuilderError::invalid_field("tag", "must be lowercase"));
+        }
+
+        Ok(value)
+    }
+}
+
+fn main() {
+    let mut builder = Post::builder();
+    builder.tag("rust").unwrap().tag("macros").unwrap();
+
+    let err = builder.tag("Derive").unwrap_err();
+    assert!(matches!(
+        err,
+        PostBuilderError::InvalidField { ref field_name, .. } if field_name == "tags[2]"
+    ));
+
+    let post = builder.build();
+    assert_eq!(post.tags, vec!["rust", "macros"]);
+}
@@ -0,0 +1,41 @@
+// The struct itself may declare several validators. They run in declaration
+// order after the target has been constructed, each receiving the value
+// returned by the previous one.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(validate = Self::ordered, validate = Self::short)]
+pub struct Window {
+    start: u32,
+    end: u32,
+}
+
+impl WindowBuilder {
+    fn ordered(window: Window) -> Result<Window, WindowBuilderError> {
+        if window.start > window.end {
+            return Err(WindowBuilderError::invalid_state("start must not come after end"));
+        }
+
+        Ok(window)
+    }
+
+    fn short(window: Window) -> Result<Window, WindowBuilderError> {
+        if window.end - window.start > 10 {
+            return Err(WindowBuilderError::invalid_state("window is too long"));
+        }
+
+        Ok(window)
+    }
+}
+
+fn main() {
+    let window = Window::builder().start(1_u32).end(5_u32).build().unwrap();
+    assert_eq!((window.start, window.end), (1, 5));
+
+    let err = Window::builder().start(5_u32).end(1_u32).build().unwrap_err();
+    assert!(err.to_string().contains("start must not come after end"));
+
+    let err = Window::builder().start(1_u32).end(50_u32).build().unwrap_err();
+    assert!(err.to_string().contains("window is too long"));
+}
@@ -0,0 +1,40 @@
+// Cross-field constraints are easiest to check before the target exists.
+//
+// #[builder(pre_validate = path)] on the struct names a function receiving
+// `&{Struct}Builder`. It runs in `build()` once every required field is
+// present, but before the target struct is constructed.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(pre_validate = Self::ordered)]
+pub struct Window {
+    start: u32,
+    end: Option<u32>,
+}
+
+impl WindowBuilder {
+    fn ordered(builder: &WindowBuilder) -> Result<(), WindowBuilderError> {
+        match (builder.get_start(), builder.get_end()) {
+            (Some(start), Some(end)) if start > end => Err(WindowBuilderError::invalid_field(
+                "end",
+                "must not come before `start`",
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn main() {
+    let window = Window::builder().start(1_u32).end(5_u32).build().unwrap();
+    assert_eq!((window.start, window.end), (1, Some(5)));
+
+    let window = Window::builder().start(1_u32).build().unwrap();
+    assert_eq!(window.end, None);
+
+    let err = Window::builder().start(5_u32).end(1_u32).build().unwrap_err();
+    assert!(matches!(
+        err,
+        WindowBuilderError::InvalidField { ref field_name, .. } if field_name == "end"
+    ));
+}
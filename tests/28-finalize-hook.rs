@@ -0,0 +1,35 @@
+// #[builder(finalize = path)] on the struct names a function which takes the
+// freshly built target by value and returns it, possibly normalized, right
+// before `build()` hands it back. It runs after all validators have passed.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(finalize = Self::normalize)]
+pub struct Post {
+    title: String,
+    #[builder(each = "tag")]
+    tags: Vec<String>,
+}
+
+impl PostBuilder {
+    fn normalize(mut post: Post) -> Post {
+        post.title = post.title.trim().to_owned();
+        post.tags.sort();
+        post.tags.dedup();
+        post
+    }
+}
+
+fn main() {
+    let post = Post::builder()
+        .title("  Hello  ")
+        .tag("rust")
+        .tag("macros")
+        .tag("rust")
+        .build()
+        .unwrap();
+
+    assert_eq!(post.title, "Hello");
+    assert_eq!(post.tags, vec!["macros", "rust"]);
+}
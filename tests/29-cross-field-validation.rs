@@ -0,0 +1,34 @@
+// #[builder(cross_validate(fields(a, b, ...), with = path))] on the struct runs
+// `path(&a, &b, ...)` against the listed fields of the built value. The
+// validator returns `Result<(), E>` for any `E: Display`, and a failure is
+// reported as an error naming every listed field.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(cross_validate(fields(start, end), with = ordered))]
+pub struct Window {
+    start: u32,
+    end: u32,
+    label: Option<String>,
+}
+
+fn ordered(start: &u32, end: &u32) -> Result<(), String> {
+    if start > end {
+        return Err(format!("{start} comes after {end}"));
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let window = Window::builder().start(1_u32).end(5_u32).build().unwrap();
+    assert_eq!((window.start, window.end), (1, 5));
+    assert!(window.label.is_none());
+
+    let err = Window::builder().start(5_u32).end(1_u32).build().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to build Window: invalid field(s) `start`, `end`: 5 comes after 1"
+    );
+}
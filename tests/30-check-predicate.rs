@@ -0,0 +1,51 @@
+// Simple validations are usually a yes/no question plus a message.
+//
+// #[builder(check(predicate = ..., message = "..."))] takes a path or closure
+// receiving `&T` and returning `bool`. When it returns `false`, the setter
+// fails with an `InvalidField` error carrying the given message. Checks and
+// validators run in declaration order.
+
+use turann::Builder;
+
+fn is_lowercase(value: &String) -> bool {
+    !value.chars().any(char::is_uppercase)
+}
+
+#[derive(Debug, Builder)]
+pub struct Account {
+    #[builder(check(predicate = is_lowercase, message = "must be lowercase"))]
+    #[builder(check(predicate = |value: &String| !value.is_empty(), message = "cannot be empty"))]
+    username: String,
+    #[builder(check(predicate = |age: &u8| *age >= 18, message = "must be an adult"))]
+    age: Option<u8>,
+}
+
+fn main() {
+    let account = Account::builder()
+        .username("ferris")
+        .unwrap()
+        .age(30_u8)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(account.username, "ferris");
+    assert_eq!(account.age, Some(30));
+
+    let err = Account::builder().username("Ferris").unwrap_err();
+    assert!(matches!(
+        err,
+        AccountBuilderError::InvalidField { ref message, .. } if message == "must be lowercase"
+    ));
+
+    let err = Account::builder().username("").unwrap_err();
+    assert!(matches!(
+        err,
+        AccountBuilderError::InvalidField { ref message, .. } if message == "cannot be empty"
+    ));
+
+    let err = Account::builder().age(3_u8).unwrap_err();
+    assert!(matches!(
+        err,
+        AccountBuilderError::InvalidField { ref field_name, .. } if field_name == "age"
+    ));
+}
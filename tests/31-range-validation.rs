@@ -0,0 +1,45 @@
+// #[builder(range = ...)] rejects values outside of the given range with an
+// `InvalidField` error describing the accepted range. Any range expression
+// with a `contains` method works.
+
+use turann::Builder;
+
+const MAX_WORKERS: usize = 64;
+
+#[derive(Debug, Builder)]
+pub struct Server {
+    #[builder(range = 1..=65535)]
+    port: u32,
+    #[builder(range = 1..MAX_WORKERS)]
+    workers: Option<usize>,
+    #[builder(range = -1.0..=1.0)]
+    balance: f64,
+}
+
+fn main() {
+    let server = Server::builder()
+        .port(8080_u32)
+        .unwrap()
+        .workers(4_usize)
+        .unwrap()
+        .balance(0.5)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.workers, Some(4));
+
+    let err = Server::builder().port(0_u32).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to assign field `port`: must be in range 1..=65535"
+    );
+
+    let err = Server::builder().workers(MAX_WORKERS).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to assign field `workers`: must be in range 1..MAX_WORKERS"
+    );
+
+    assert!(Server::builder().balance(1.5).is_err());
+}
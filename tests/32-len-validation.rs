@@ -0,0 +1,55 @@
+// #[builder(len = ...)] constrains the `len()` of strings, vectors, and any
+// other type with a `len` method. Plain fields are checked in the setter;
+// collections built with `each` are checked in `build()` once every element
+// has been pushed. The error message shows the range as it was written.
+
+use turann::Builder;
+
+const MAX_TAGS: u8 = 2;
+
+#[derive(Debug, Builder)]
+pub struct Account {
+    #[builder(len = 1..=8)]
+    username: String,
+    #[builder(len = ..3)]
+    nicknames: Option<Vec<String>>,
+    #[builder(len = 0..=MAX_TAGS as usize)]
+    tags: Option<Vec<String>>,
+    #[builder(each = "role", len = 1..)]
+    roles: Vec<String>,
+}
+
+fn main() {
+    let account = Account::builder()
+        .username("ferris")
+        .unwrap()
+        .role("admin")
+        .build()
+        .unwrap();
+    assert_eq!(account.username, "ferris");
+    assert_eq!(account.roles, vec!["admin"]);
+
+    let err = Account::builder().username("a very long name").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to assign field `username`: length must be in range 1..=8"
+    );
+
+    assert!(Account::builder().nicknames(vec![String::new(); 3]).is_err());
+
+    let err = Account::builder().tags(vec![String::new(); 3]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to assign field `tags`: length must be in range 0..=MAX_TAGS as usize"
+    );
+
+    let err = Account::builder()
+        .username("ferris")
+        .unwrap()
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to assign field `roles`: length must be in range 1.."
+    );
+}
@@ -0,0 +1,34 @@
+// #[builder(non_empty)] rejects empty strings and empty collections with a
+// standard "cannot be empty" message. Collections built with `each` are
+// checked in `build()`.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Post {
+    #[builder(non_empty)]
+    title: String,
+    #[builder(non_empty)]
+    summary: Option<String>,
+    #[builder(each = "tag", non_empty)]
+    tags: Vec<String>,
+}
+
+fn main() {
+    let post = Post::builder()
+        .title("Hello")
+        .unwrap()
+        .tag("rust")
+        .build()
+        .unwrap();
+    assert_eq!(post.title, "Hello");
+    assert!(post.summary.is_none());
+
+    let err = Post::builder().title("").unwrap_err();
+    assert_eq!(err.to_string(), "Unable to assign field `title`: cannot be empty");
+
+    assert!(Post::builder().summary("").is_err());
+
+    let err = Post::builder().title("Hello").unwrap().build().unwrap_err();
+    assert_eq!(err.to_string(), "Unable to assign field `tags`: cannot be empty");
+}
@@ -0,0 +1,36 @@
+// With the `regex` feature enabled, #[builder(matches = "...")] rejects string
+// values that don't match the pattern. The compiled regex is cached in a
+// `LazyLock`, so it is only built once. The caller's crate needs to depend on
+// `regex`.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Package {
+    #[builder(matches = "^[a-z0-9_-]+$", matches = "^[a-z]")]
+    name: String,
+    #[builder(matches = r"^\d+\.\d+\.\d+$")]
+    version: Option<String>,
+}
+
+fn main() {
+    let package = Package::builder()
+        .name("turann")
+        .unwrap()
+        .version("1.1.0")
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(package.name, "turann");
+
+    let err = Package::builder().name("Turann").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to assign field `name`: must match `^[a-z0-9_-]+$`"
+    );
+
+    let err = Package::builder().name("1turann").unwrap_err();
+    assert_eq!(err.to_string(), "Unable to assign field `name`: must match `^[a-z]`");
+
+    assert!(Package::builder().version("latest").is_err());
+}
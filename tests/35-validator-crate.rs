@@ -0,0 +1,36 @@
+// Structs that already derive `validator::Validate` can opt into running those
+// checks from `build()` with #[builder(use_validator)]. `ValidationErrors` are
+// converted into a `ValidationFailed` error listing the failing fields. The
+// caller's crate needs to depend on `validator`.
+
+use turann::Builder;
+use validator::Validate;
+
+#[derive(Debug, Builder, Validate)]
+#[builder(use_validator)]
+pub struct SignupData {
+    #[validate(email)]
+    mail: String,
+    #[validate(range(min = 18, max = 20))]
+    age: u32,
+}
+
+fn main() {
+    let data = SignupData::builder()
+        .mail("ferris@example.com")
+        .age(18_u32)
+        .build()
+        .unwrap();
+    assert_eq!(data.age, 18);
+
+    let err = SignupData::builder()
+        .mail("not an email")
+        .age(18_u32)
+        .build()
+        .unwrap_err();
+    assert!(matches!(
+        &err,
+        SignupDataBuilderError::ValidationFailed { fields, .. } if fields == &["mail"]
+    ));
+    assert!(err.to_string().contains("mail"));
+}
@@ -0,0 +1,93 @@
+// #[builder(async_validate = path)] on the struct names an async function
+// which takes the built target by value and returns
+// `Result<Target, TargetBuilderError>`, like a regular struct validator. When
+// at least one is present the builder also gets `async fn build_async(&self)`,
+// which runs everything `build()` does and then awaits each async validator in
+// declaration order. The sync `build()` is unchanged and skips them.
+
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(async_validate = Self::username_is_free, async_validate = Self::domain_resolves)]
+pub struct Account {
+    username: String,
+    domain: String,
+}
+
+impl AccountBuilder {
+    async fn username_is_free(account: Account) -> Result<Account, AccountBuilderError> {
+        if account.username == "taken" {
+            return Err(AccountBuilderError::invalid_field(
+                "username",
+                "is already registered",
+            ));
+        }
+
+        Ok(account)
+    }
+
+    async fn domain_resolves(account: Account) -> Result<Account, AccountBuilderError> {
+        if !account.domain.contains('.') {
+            return Err(AccountBuilderError::invalid_field(
+                "domain",
+                "does not resolve",
+            ));
+        }
+
+        Ok(account)
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+fn main() {
+    let account = block_on(
+        Account::builder()
+            .username("ferris")
+            .domain("rust-lang.org")
+            .build_async(),
+    )
+    .unwrap();
+    assert_eq!(account.username, "ferris");
+
+    let err = block_on(
+        Account::builder()
+            .username("taken")
+            .domain("rust-lang.org")
+            .build_async(),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("username"));
+
+    let err = block_on(
+        Account::builder()
+            .username("ferris")
+            .domain("localhost")
+            .build_async(),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("domain"));
+
+    assert!(block_on(Account::builder().domain("rust-lang.org").build_async()).is_err());
+
+    // The sync build does not run async validators.
+    assert!(
+        Account::builder()
+            .username("taken")
+            .domain("localhost")
+            .build()
+            .is_ok()
+    );
+}
@@ -0,0 +1,110 @@
+// Validators may return their own error types, as long as the builder error
+// implements `From` for them. Field and item validators convert the error in
+// the generated setter, before it is tagged with the caller location, while
+// struct-level validators go through `?` as usual.
+//
+// With `display_errors` on the field, the error type only needs `Display`: the
+// field and item validators' errors become an `InvalidField` error carrying
+// the displayed message, with the `invalid_value` code unless `code` is set.
+
+use turann::Builder;
+
+#[derive(Debug)]
+pub enum DomainError {
+    Blank,
+    Reserved(String),
+}
+
+impl From<DomainError> for UserBuilderError {
+    fn from(err: DomainError) -> Self {
+        match err {
+            DomainError::Blank => UserBuilderError::invalid_field("name", "cannot be blank"),
+            DomainError::Reserved(name) => {
+                UserBuilderError::invalid_field("name", format!("`{name}` is reserved"))
+            }
+        }
+    }
+}
+
+fn not_blank(value: String) -> Result<String, DomainError> {
+    if value.trim().is_empty() {
+        return Err(DomainError::Blank);
+    }
+
+    Ok(value)
+}
+
+#[derive(Debug)]
+pub struct TooShort(usize);
+
+impl std::fmt::Display for TooShort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "must be at least {} characters long", self.0)
+    }
+}
+
+fn long_enough(value: String) -> Result<String, TooShort> {
+    if value.len() < 8 {
+        return Err(TooShort(8));
+    }
+
+    Ok(value)
+}
+
+fn not_reserved(user: User) -> Result<User, DomainError> {
+    if user.name == "root" {
+        return Err(DomainError::Reserved(user.name));
+    }
+
+    Ok(user)
+}
+
+#[derive(Debug, Builder)]
+#[builder(validate = not_reserved)]
+pub struct User {
+    #[builder(validate = not_blank)]
+    name: String,
+    #[builder(each = "group", validate_item = not_blank)]
+    groups: Vec<String>,
+    #[builder(validate = long_enough, display_errors)]
+    password: String,
+}
+
+fn main() {
+    let user = User::builder()
+        .name("ferris")
+        .unwrap()
+        .group("crabs")
+        .unwrap()
+        .password("correct horse")
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(user.name, "ferris");
+
+    let err = User::builder().name("  ").unwrap_err();
+    assert!(matches!(
+        err,
+        UserBuilderError::InvalidField { location: Some(_), .. }
+    ));
+    assert_eq!(err.to_string(), "Unable to assign field `name`: cannot be blank");
+
+    let err = User::builder().group("").unwrap_err();
+    assert!(err.to_string().contains("groups[0]"));
+
+    let err = User::builder().password("hunter2").unwrap_err();
+    assert_eq!(err.error_code(), "invalid_value");
+    assert_eq!(
+        err.to_string(),
+        "Unable to assign field `password`: must be at least 8 characters long"
+    );
+
+    let err = User::builder()
+        .name("root")
+        .unwrap()
+        .password("correct horse")
+        .unwrap()
+        .build()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Unable to assign field `name`: `root` is reserved");
+}
@@ -0,0 +1,77 @@
+// With #[builder(defer_errors)] on the struct, validated setters return
+// `&mut Self` like every other setter. Failures are recorded on the builder
+// instead, and `build()` reports all of them at once, along with missing
+// fields and every other check, so a configuration is fixed in one pass. A
+// field whose setter failed is not reported as missing as well. A single
+// failure is reported as is, several are grouped into the `Multiple` variant.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(defer_errors)]
+pub struct Server {
+    #[builder(non_empty)]
+    host: String,
+    #[builder(range = 1..=65535)]
+    port: u32,
+    #[builder(each = "alias", validate_item = Self::lowercase)]
+    aliases: Vec<String>,
+}
+
+impl ServerBuilder {
+    fn lowercase(value: String) -> Result<String, ServerBuilderError> {
+        if value.chars().any(char::is_uppercase) {
+            return Err(ServerBuilderError::invalid_field("alias", "must be lowercase"));
+        }
+
+        Ok(value)
+    }
+}
+
+fn main() {
+    let server = Server::builder()
+        .host("localhost")
+        .port(8080_u32)
+        .alias("local")
+        .build()
+        .unwrap();
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.aliases, vec!["local"]);
+
+    let err = Server::builder()
+        .host("localhost")
+        .port(0_u32)
+        .build()
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ServerBuilderError::InvalidField { location: Some(_), .. }
+    ));
+
+    let err = Server::builder()
+        .host("")
+        .port(0_u32)
+        .alias("ok")
+        .alias("NOPE")
+        .build()
+        .unwrap_err();
+    let ServerBuilderError::Multiple { errors } = &err else {
+        panic!("expected every setter error, got {err:?}");
+    };
+    assert_eq!(errors.len(), 3);
+    assert_eq!(
+        err.to_string(),
+        "Unable to build Server: 3 errors (\
+        Unable to assign field `host`: cannot be empty; \
+        Unable to assign field `port`: must be in range 1..=65535; \
+        Unable to assign field `aliases[1]`: must be lowercase)"
+    );
+
+    let err = Server::builder().port(0_u32).build().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to build Server: 2 errors (\
+        Unable to assign field `port`: must be in range 1..=65535; \
+        Unable to build Server: missing required field(s): `host: String`)"
+    );
+}
@@ -0,0 +1,172 @@
+// `build()` does not stop at the first problem. Missing fields and collection
+// checks are reported together, along with every pre-build validator failure
+// once all required fields are present. When those pass, every cross-field
+// validation runs and their failures are reported together as well. A single
+// failure is returned as is, several are grouped into the `Multiple` variant.
+// Struct validators are the exception: each takes the built value by value
+// and hands it on to the next, so they only run once everything else passed,
+// and the first one to fail is reported on its own. Grouped errors display on
+// a single line for logs, while the alternate formatter (`{:#}`) prints a
+// report with one error per line.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(pre_validate = Self::no_localhost)]
+#[builder(cross_validate(fields(min_workers, max_workers), with = ordered))]
+#[builder(cross_validate(fields(port, admin_port), with = distinct))]
+pub struct Config {
+    host: String,
+    port: u16,
+    admin_port: u16,
+    min_workers: u32,
+    max_workers: u32,
+    #[builder(each = "upstream", non_empty)]
+    upstreams: Vec<String>,
+}
+
+impl ConfigBuilder {
+    fn no_localhost(&self) -> Result<(), ConfigBuilderError> {
+        if self.host.as_deref() == Some("localhost") {
+            return Err(ConfigBuilderError::invalid_field("host", "cannot be localhost"));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(cross_validate(fields(start, end), with = ordered))]
+#[builder(validate = Self::short, validate = Self::aligned)]
+pub struct Window {
+    start: u32,
+    end: u32,
+}
+
+impl WindowBuilder {
+    fn short(window: Window) -> Result<Window, WindowBuilderError> {
+        if window.end - window.start > 10 {
+            return Err(WindowBuilderError::invalid_state("window is too long"));
+        }
+
+        Ok(window)
+    }
+
+    fn aligned(window: Window) -> Result<Window, WindowBuilderError> {
+        if window.start % 2 != 0 {
+            return Err(WindowBuilderError::invalid_state("window is not aligned"));
+        }
+
+        Ok(window)
+    }
+}
+
+fn ordered(min: &u32, max: &u32) -> Result<(), String> {
+    if min > max {
+        return Err(format!("{min} is greater than {max}"));
+    }
+
+    Ok(())
+}
+
+fn distinct(a: &u16, b: &u16) -> Result<(), &'static str> {
+    if a == b {
+        return Err("must differ");
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let err = Config::builder()
+        .host("localhost")
+        .port(80_u16)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        format!("{err:#}"),
+        "Unable to build Config: 2 errors\
+        \n  - Unable to build Config: missing required field(s): `admin_port: u16`, `min_workers: u32`, `max_workers: u32`\
+        \n  - Unable to assign field `upstreams`: cannot be empty"
+    );
+    assert!(!err.to_string().contains('\n'));
+
+    let err = Config::builder()
+        .host("localhost")
+        .port(80_u16)
+        .admin_port(80_u16)
+        .min_workers(8_u32)
+        .max_workers(4_u32)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ConfigBuilderError::multiple([
+            ConfigBuilderError::invalid_field("upstreams", "cannot be empty").with_code("empty"),
+            ConfigBuilderError::invalid_field("host", "cannot be localhost"),
+        ])
+    );
+
+    let err = Config::builder()
+        .host("example.com")
+        .port(80_u16)
+        .admin_port(80_u16)
+        .min_workers(8_u32)
+        .max_workers(4_u32)
+        .upstream("a")
+        .build()
+        .unwrap_err();
+    let ConfigBuilderError::Multiple { errors } = err else {
+        panic!("expected both cross-field failures, got {err:?}");
+    };
+    assert_eq!(
+        errors,
+        vec![
+            ConfigBuilderError::invalid_fields(
+                &["min_workers", "max_workers"],
+                "8 is greater than 4"
+            ),
+            ConfigBuilderError::invalid_fields(&["port", "admin_port"], "must differ"),
+        ]
+    );
+
+    let err = Config::builder()
+        .host("example.com")
+        .port(80_u16)
+        .admin_port(8080_u16)
+        .min_workers(1_u32)
+        .max_workers(4_u32)
+        .build()
+        .unwrap_err();
+    assert_eq!(err, ConfigBuilderError::invalid_field("upstreams", "cannot be empty").with_code("empty"));
+
+    // a failing cross-field validation keeps the struct validators from
+    // running
+    let err = Window::builder()
+        .start(9_u32)
+        .end(1_u32)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        WindowBuilderError::invalid_fields(&["start", "end"], "9 is greater than 1")
+    );
+
+    // only the first failing struct validator is reported
+    let err = Window::builder()
+        .start(1_u32)
+        .end(50_u32)
+        .build()
+        .unwrap_err();
+    assert_eq!(err, WindowBuilderError::invalid_state("window is too long"));
+
+    let err = Window::builder()
+        .start(1_u32)
+        .end(5_u32)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        WindowBuilderError::invalid_state("window is not aligned")
+    );
+}
@@ -0,0 +1,56 @@
+// Builders populated from forms or environment variables often receive empty
+// strings for values which were not provided. #[builder(treat_empty_as_missing)]
+// on the struct makes empty `String` and `Vec` fields count as missing in
+// `build()`, and makes fields with a default fall back to it when empty. The
+// same attribute on a single field applies it to any type with an `is_empty`
+// method.
+
+use std::borrow::Cow;
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(treat_empty_as_missing)]
+pub struct Form {
+    name: String,
+    #[builder(default = Self::anonymous)]
+    nickname: String,
+    #[builder(each = "tag")]
+    tags: Vec<String>,
+    age: u32,
+    comment: Option<String>,
+}
+
+impl FormBuilder {
+    fn anonymous() -> String {
+        "anonymous".to_owned()
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct Env {
+    #[builder(treat_empty_as_missing)]
+    home: Cow<'static, str>,
+    shell: String,
+}
+
+fn main() {
+    let form = Form::builder()
+        .name("Ferris")
+        .nickname("")
+        .tag("crab")
+        .age(7_u32)
+        .comment("")
+        .build()
+        .unwrap();
+    assert_eq!(form.nickname, "anonymous");
+    assert_eq!(form.comment.as_deref(), Some(""));
+
+    let err = Form::builder().name("").age(7_u32).build().unwrap_err();
+    assert_eq!(err, FormBuilderError::missing_fields(&["name: String", "tags: Vec<String>"]));
+
+    let err = Env::builder().home("").shell("").build().unwrap_err();
+    assert_eq!(err, EnvBuilderError::missing_field("home: Cow<'static, str>"));
+
+    let env = Env::builder().home("/root").shell("").build().unwrap();
+    assert_eq!(env.shell, "");
+}
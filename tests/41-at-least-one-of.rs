@@ -0,0 +1,45 @@
+// #[builder(at_least_one_of(a, b, ...))] on the struct requires that at least
+// one of the listed fields has been set by the time `build()` is called. The
+// listed fields are usually optional, and a struct may declare several groups.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(at_least_one_of(email, phone))]
+#[builder(at_least_one_of(street, po_box))]
+pub struct Contact {
+    name: String,
+    email: Option<String>,
+    phone: Option<String>,
+    street: Option<String>,
+    po_box: Option<u32>,
+}
+
+fn main() {
+    let contact = Contact::builder()
+        .name("Ferris")
+        .phone("555-0100")
+        .po_box(42_u32)
+        .build()
+        .unwrap();
+    assert_eq!(contact.email, None);
+
+    let err = Contact::builder()
+        .name("Ferris")
+        .street("1 Crab Lane")
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to build Contact: at least one of `email`, `phone` must be set"
+    );
+
+    let err = Contact::builder().name("Ferris").build().unwrap_err();
+    assert_eq!(
+        err,
+        ContactBuilderError::multiple([
+            ContactBuilderError::invalid_state("at least one of `email`, `phone` must be set"),
+            ContactBuilderError::invalid_state("at least one of `street`, `po_box` must be set"),
+        ])
+    );
+}
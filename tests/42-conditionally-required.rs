@@ -0,0 +1,58 @@
+// #[builder(required_if = "other")] makes an optional or defaulted field
+// required whenever `other` is switched on, and #[builder(required_unless =
+// "other")] whenever it is not. A `bool` field is switched on when `true`,
+// any other field when it has been set. Unmet conditions are reported as
+// missing fields.
+
+use std::path::PathBuf;
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Listener {
+    port: u16,
+    #[builder(default)]
+    tls: bool,
+    #[builder(required_if = "tls")]
+    cert_path: Option<PathBuf>,
+    #[builder(required_if = "tls")]
+    key_path: Option<PathBuf>,
+    socket: Option<PathBuf>,
+    #[builder(default = Self::any_host, required_unless = "socket")]
+    host: String,
+}
+
+impl ListenerBuilder {
+    fn any_host() -> String {
+        "0.0.0.0".to_owned()
+    }
+}
+
+fn main() {
+    let listener = Listener::builder()
+        .port(80_u16)
+        .host("localhost")
+        .build()
+        .unwrap();
+    assert!(!listener.tls);
+    assert_eq!(listener.cert_path, None);
+
+    let err = Listener::builder()
+        .port(443_u16)
+        .tls(true)
+        .cert_path("cert.pem")
+        .host("localhost")
+        .build()
+        .unwrap_err();
+    assert_eq!(err, ListenerBuilderError::missing_field("key_path: PathBuf"));
+
+    let err = Listener::builder().port(80_u16).build().unwrap_err();
+    assert_eq!(err, ListenerBuilderError::missing_field("host: String"));
+
+    let listener = Listener::builder()
+        .port(80_u16)
+        .tls(false)
+        .socket("/run/app.sock")
+        .build()
+        .unwrap();
+    assert_eq!(listener.host, "0.0.0.0");
+}
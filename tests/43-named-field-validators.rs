@@ -0,0 +1,70 @@
+// #[builder(validate_with_name = path)] names a validator which also receives
+// the name of the field being set, as `fn(&'static str, T) -> Result<T, E>`.
+// This lets one validator be shared between fields while still producing
+// errors that point at the right one. It runs in declaration order alongside
+// the other field validations.
+
+use turann::Builder;
+
+fn not_blank(field_name: &'static str, value: String) -> Result<String, ProfileBuilderError> {
+    if value.trim().is_empty() {
+        return Err(ProfileBuilderError::invalid_field(
+            field_name,
+            "cannot be blank",
+        ));
+    }
+
+    Ok(value)
+}
+
+fn positive(field_name: &'static str, value: i32) -> Result<i32, ProfileBuilderError> {
+    if value <= 0 {
+        return Err(ProfileBuilderError::invalid_field(
+            field_name,
+            format!("must be positive, got {value}"),
+        ));
+    }
+
+    Ok(value)
+}
+
+#[derive(Debug, Builder)]
+pub struct Profile {
+    #[builder(validate_with_name = not_blank)]
+    first_name: String,
+    #[builder(validate_with_name = not_blank)]
+    last_name: String,
+    #[builder(validate_with_name = positive)]
+    height: i32,
+    #[builder(each = "alias", validate_with_name = not_blank)]
+    aliases: Vec<String>,
+}
+
+fn main() {
+    let profile = Profile::builder()
+        .first_name("Ferris")
+        .unwrap()
+        .last_name("Crab")
+        .unwrap()
+        .height(12)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(profile.last_name, "Crab");
+    assert!(profile.aliases.is_empty());
+
+    let err = Profile::builder().last_name(" ").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to assign field `last_name`: cannot be blank"
+    );
+
+    let err = Profile::builder().height(-3).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to assign field `height`: must be positive, got -3"
+    );
+
+    let err = Profile::builder().alias("").unwrap_err();
+    assert!(err.to_string().contains("`aliases[0]`"));
+}
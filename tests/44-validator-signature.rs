@@ -0,0 +1,18 @@
+// A validator with the wrong signature is reported at the attribute naming it,
+// along with the signature the builder expected.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Account {
+    #[builder(validate = Self::short)]
+    username: String,
+}
+
+impl AccountBuilder {
+    fn short(value: &str) -> bool {
+        value.len() <= 8
+    }
+}
+
+fn main() {}
@@ -0,0 +1,79 @@
+// #[builder(validate_order(...))] on the struct chooses the order in which
+// `build()` runs its validation stages:
+//
+//   - `missing`: required fields, `at_least_one_of` and conditional requirements
+//   - `fields`: collection checks such as `len` and `non_empty` on `each` fields
+//   - `pre`: `pre_validate` functions receiving the builder
+//   - `cross`: `cross_validate` functions and validation crate integrations
+//   - `struct`: `validate` functions receiving the built value
+//
+// Stages left out keep their default position after the listed ones. Listing
+// `pre` before `missing` runs pre-build validators even when required fields
+// are missing, so everything can be reported at once. `cross` and `struct`
+// need the built value and must come after `missing`.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(validate_order(pre, missing, struct, cross))]
+#[builder(pre_validate = Self::known_region)]
+#[builder(validate = Self::normalize)]
+#[builder(cross_validate(fields(name, region), with = distinct))]
+pub struct Bucket {
+    name: String,
+    region: Option<String>,
+}
+
+impl BucketBuilder {
+    fn known_region(&self) -> Result<(), BucketBuilderError> {
+        match self.region.as_deref() {
+            None | Some("eu" | "us") => Ok(()),
+            Some(region) => Err(BucketBuilderError::invalid_field(
+                "region",
+                format!("unknown region `{region}`"),
+            )),
+        }
+    }
+
+    fn normalize(mut bucket: Bucket) -> Result<Bucket, BucketBuilderError> {
+        bucket.name = bucket.name.to_lowercase();
+        Ok(bucket)
+    }
+}
+
+fn distinct(name: &String, region: &Option<String>) -> Result<(), &'static str> {
+    if region.as_ref() == Some(name) {
+        return Err("a bucket cannot be named after its region");
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let err = Bucket::builder().region("mars").build().unwrap_err();
+    assert_eq!(
+        err,
+        BucketBuilderError::multiple([
+            BucketBuilderError::invalid_field("region", "unknown region `mars`"),
+            BucketBuilderError::missing_field("name: String"),
+        ])
+    );
+
+    // `struct` runs before `cross`, so the cross-field check sees the
+    // normalized name.
+    let err = Bucket::builder()
+        .name("EU")
+        .region("eu")
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        BucketBuilderError::invalid_fields(
+            &["name", "region"],
+            "a bucket cannot be named after its region"
+        )
+    );
+
+    let bucket = Bucket::builder().name("Logs").build().unwrap();
+    assert_eq!(bucket.name, "logs");
+}
@@ -0,0 +1,80 @@
+// Every builder error has an `error_code()` returning a stable identifier, so
+// services can map failures without matching on messages. Built-in
+// validations use `check_failed`, `out_of_range`, `invalid_length`, `empty`
+// and `pattern_mismatch`, and validator errors converted with
+// `display_errors` use `invalid_value`, which #[builder(code = "...")] on the
+// field replaces. Missing fields report `missing_fields`, cross-field validations
+// `invalid_fields`, and errors without a specific code fall back to
+// `invalid_state` or `invalid_field`. Validators can attach their own code
+// with `with_code`.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(cross_validate(fields(min, max), with = ordered))]
+pub struct Limits {
+    #[builder(range = 1..=100)]
+    min: u32,
+    #[builder(range = 1..=100, code = "max_out_of_bounds")]
+    max: u32,
+    #[builder(validate = Self::known_unit)]
+    unit: String,
+    #[builder(each = "tag", non_empty)]
+    tags: Vec<String>,
+}
+
+impl LimitsBuilder {
+    fn known_unit(value: String) -> Result<String, LimitsBuilderError> {
+        if value != "ms" && value != "s" {
+            return Err(
+                LimitsBuilderError::invalid_field("unit", "unknown unit").with_code("unknown_unit"),
+            );
+        }
+
+        Ok(value)
+    }
+}
+
+fn ordered(min: &u32, max: &u32) -> Result<(), &'static str> {
+    if min > max {
+        return Err("min must not exceed max");
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let mut builder = Limits::builder();
+
+    assert_eq!(builder.min(0_u32).unwrap_err().error_code(), "out_of_range");
+    assert_eq!(
+        builder.max(500_u32).unwrap_err().error_code(),
+        "max_out_of_bounds"
+    );
+    assert_eq!(builder.unit("h").unwrap_err().error_code(), "unknown_unit");
+
+    let err = builder.build().unwrap_err();
+    assert_eq!(err.error_code(), "multiple");
+    let LimitsBuilderError::Multiple { errors } = err else {
+        unreachable!();
+    };
+    let codes: Vec<_> = errors.iter().map(LimitsBuilderError::error_code).collect();
+    assert_eq!(codes, vec!["missing_fields", "empty"]);
+
+    let err = Limits::builder()
+        .min(50_u32)
+        .unwrap()
+        .max(10_u32)
+        .unwrap()
+        .unit("ms")
+        .unwrap()
+        .tag("a")
+        .build()
+        .unwrap_err();
+    assert_eq!(err.error_code(), "invalid_fields");
+
+    assert_eq!(
+        LimitsBuilderError::invalid_state("anything").error_code(),
+        "invalid_state"
+    );
+}
@@ -0,0 +1,74 @@
+// With the `serde` feature enabled, the generated error implements
+// `serde::Serialize`, so services can return it as JSON. Variants keep their
+// field names and messages, gain their `error_code()`, and drop the setter
+// call location. The report from `to_report()` serializes as well. The
+// caller's crate needs to depend on `serde`.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct User {
+    #[builder(non_empty)]
+    name: String,
+    age: u8,
+}
+
+fn main() {
+    let err = User::builder().name("").unwrap_err();
+    assert_eq!(
+        serde_json::to_value(&err).unwrap(),
+        serde_json::json!({
+            "InvalidField": {
+                "field_name": "name",
+                "message": "cannot be empty",
+                "code": "empty",
+            }
+        })
+    );
+
+    let err = UserBuilderError::multiple([
+        UserBuilderError::missing_field("age"),
+        UserBuilderError::invalid_state("too young"),
+    ]);
+    assert_eq!(
+        serde_json::to_value(&err).unwrap(),
+        serde_json::json!({
+            "Multiple": {
+                "errors": [
+                    {
+                        "InvalidState": {
+                            "message": "missing required field(s): `age`",
+                            "code": "missing_fields",
+                        }
+                    },
+                    {
+                        "InvalidState": {
+                            "message": "too young",
+                            "code": "invalid_state",
+                        }
+                    },
+                ]
+            }
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(err.to_report()).unwrap(),
+        serde_json::json!({
+            "errors": [
+                {
+                    "field": null,
+                    "kind": "invalid_state",
+                    "code": "missing_fields",
+                    "message": "missing required field(s): `age`",
+                },
+                {
+                    "field": null,
+                    "kind": "invalid_state",
+                    "code": "invalid_state",
+                    "message": "too young",
+                },
+            ]
+        })
+    );
+}
@@ -0,0 +1,56 @@
+// `InvalidField` errors can carry the underlying error which caused them, and
+// expose it through `std::error::Error::source()` so error reporters show the
+// whole chain. `with_source` attaches one, typically in the `From` conversion
+// of a validator's own error type. The source is not part of the error's
+// identity, so it is ignored by `==`.
+
+use std::error::Error;
+use std::fmt;
+use turann::Builder;
+
+#[derive(Debug)]
+pub struct PortTaken(u16);
+
+impl fmt::Display for PortTaken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "port {} is already in use", self.0)
+    }
+}
+
+impl Error for PortTaken {}
+
+impl From<PortTaken> for ServerBuilderError {
+    fn from(err: PortTaken) -> Self {
+        ServerBuilderError::invalid_field("port", "is unavailable").with_source(err)
+    }
+}
+
+fn available(port: u16) -> Result<u16, PortTaken> {
+    if port == 80 {
+        return Err(PortTaken(port));
+    }
+
+    Ok(port)
+}
+
+#[derive(Debug, Builder)]
+pub struct Server {
+    #[builder(validate = available)]
+    port: u16,
+}
+
+fn main() {
+    let err = Server::builder().port(80_u16).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to assign field `port`: is unavailable"
+    );
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "port 80 is already in use"
+    );
+    assert!(err.source().unwrap().downcast_ref::<PortTaken>().is_some());
+
+    assert!(ServerBuilderError::invalid_field("port", "bad").source().is_none());
+    assert!(ServerBuilderError::missing_field("port").source().is_none());
+}
@@ -0,0 +1,65 @@
+// #[builder(error_derive(...))] adds derives to the generated error enum. When
+// one of them is `thiserror::Error`, the builder leaves `Display` and `Error`
+// to thiserror and emits `#[error(...)]` attributes on the variants instead.
+//
+// #[builder(error_display(...))] replaces the message of each variant with a
+// format string, with or without thiserror. `invalid_state` can use
+// `{message}` and `{code:?}`, `invalid_field` also `{field_name}`, and
+// `multiple` gets `{count}` followed by the list of grouped errors.
+
+use std::error::Error;
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(error_derive(thiserror::Error))]
+#[builder(error_display(invalid_field = "{field_name} is invalid: {message}"))]
+pub struct Upload {
+    #[builder(non_empty)]
+    name: String,
+    size: u64,
+}
+
+#[derive(Debug, Builder)]
+#[builder(error_display(
+    invalid_state = "cannot create a download ({message})",
+    multiple = "{count} problems with the download:"
+))]
+pub struct Download {
+    #[builder(each = "mirror", non_empty)]
+    mirrors: Vec<String>,
+    retries: u8,
+}
+
+fn assert_error<E: Error>(_: &E) {}
+
+fn main() {
+    let err = Upload::builder().name("").unwrap_err();
+    assert_error(&err);
+    assert_eq!(err.to_string(), "name is invalid: cannot be empty");
+
+    let err = Upload::builder().build().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to build Upload: missing required field(s): `name: String`, `size: u64`"
+    );
+
+    let err = Download::builder().retries(3_u8).build().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to assign field `mirrors`: cannot be empty"
+    );
+
+    let err = Download::builder().build().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "2 problems with the download: (\
+        cannot create a download (missing required field(s): `retries: u8`); \
+        Unable to assign field `mirrors`: cannot be empty)"
+    );
+    assert_eq!(
+        format!("{err:#}"),
+        "2 problems with the download:\
+        \n  - cannot create a download (missing required field(s): `retries: u8`)\
+        \n  - Unable to assign field `mirrors`: cannot be empty"
+    );
+}
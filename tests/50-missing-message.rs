@@ -0,0 +1,60 @@
+// #[builder(missing_message = "...")] replaces the generic "missing required
+// field(s)" message for one field with a domain-specific one. The error is
+// reported against that field and keeps the `missing_fields` code, while the
+// remaining missing fields are still grouped together.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Client {
+    #[builder(missing_message = "an API key is required, create one in the dashboard")]
+    api_key: String,
+    #[builder(
+        required_if = "use_proxy",
+        missing_message = "a proxy needs an address"
+    )]
+    proxy: Option<String>,
+    #[builder(default)]
+    use_proxy: bool,
+    endpoint: String,
+}
+
+fn main() {
+    let err = Client::builder()
+        .endpoint("https://example.com")
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ClientBuilderError::missing_field_with_message(
+            "api_key",
+            "an API key is required, create one in the dashboard"
+        )
+    );
+    assert_eq!(err.error_code(), "missing_fields");
+    assert_eq!(
+        err.to_string(),
+        "Unable to assign field `api_key`: an API key is required, create one in the dashboard"
+    );
+
+    let err = Client::builder()
+        .api_key("secret")
+        .use_proxy(true)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ClientBuilderError::multiple([
+            ClientBuilderError::missing_field("endpoint: String"),
+            ClientBuilderError::missing_field_with_message("proxy", "a proxy needs an address"),
+        ])
+    );
+
+    let client = Client::builder()
+        .api_key("secret")
+        .endpoint("https://example.com")
+        .build()
+        .unwrap();
+    assert_eq!(client.api_key, "secret");
+    assert_eq!(client.proxy, None);
+}
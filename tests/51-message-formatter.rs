@@ -0,0 +1,44 @@
+// #[builder(message_formatter = path)] hands every error to `path` when it is
+// displayed, so applications can render localized messages. The formatter
+// receives the error itself, with its variant, field name, and code, and
+// returns the string to display. It must be a `fn(&Error) -> String`.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(message_formatter = french)]
+pub struct Account {
+    #[builder(non_empty)]
+    username: String,
+    #[builder(range = 13..=130)]
+    age: u8,
+}
+
+fn french(error: &AccountBuilderError) -> String {
+    match error {
+        AccountBuilderError::InvalidField { field_name, .. } => match error.error_code() {
+            "empty" => format!("le champ `{field_name}` ne peut pas être vide"),
+            "out_of_range" => format!("le champ `{field_name}` est hors limites"),
+            _ => format!("le champ `{field_name}` est invalide"),
+        },
+        AccountBuilderError::InvalidState { .. } if error.error_code() == "missing_fields" => {
+            "des champs obligatoires sont manquants".to_owned()
+        }
+        AccountBuilderError::InvalidState { message, .. }
+        | AccountBuilderError::ValidationFailed { message, .. } => message.to_string(),
+        AccountBuilderError::Multiple { errors } => {
+            errors.iter().map(french).collect::<Vec<_>>().join("; ")
+        }
+    }
+}
+
+fn main() {
+    let err = Account::builder().username("").unwrap_err();
+    assert_eq!(err.to_string(), "le champ `username` ne peut pas être vide");
+
+    let err = Account::builder().age(7_u8).unwrap_err();
+    assert_eq!(err.to_string(), "le champ `age` est hors limites");
+
+    let err = Account::builder().build().unwrap_err();
+    assert_eq!(err.to_string(), "des champs obligatoires sont manquants");
+}
@@ -0,0 +1,43 @@
+// Missing fields are reported together with the type their setter expects,
+// e.g. "missing `port: u16`". When a field with a similar name was set
+// instead, the error suggests the field which is actually required.
+
+use std::collections::HashMap;
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Server {
+    port: u16,
+    ports: Option<Vec<u16>>,
+    labels: HashMap<String, String>,
+    #[builder(default)]
+    verbose: bool,
+}
+
+fn main() {
+    let err = Server::builder().build().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to build Server: missing required field(s): \
+        `port: u16`, `labels: HashMap<String, String>`"
+    );
+
+    let err = Server::builder()
+        .ports(vec![80, 443])
+        .labels(HashMap::new())
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to build Server: missing required field(s): `port: u16` \
+        (`ports` was set, did you mean `port`?)"
+    );
+
+    let server = Server::builder()
+        .port(80_u16)
+        .labels(HashMap::new())
+        .build()
+        .unwrap();
+    assert_eq!(server.port, 80);
+    assert!(!server.verbose);
+}
@@ -0,0 +1,22 @@
+// Attributes which only make sense once per field, such as `each`, `default`,
+// `code`, and `missing_message`, are rejected when repeated instead of
+// silently using the first one. Attributes which cannot apply to the field,
+// like `each` on a field which is not a collection, are reported as well.
+// This is a compile_fail test.
+
+use turann::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(each = "arg")]
+    #[builder(each = "argument")]
+    args: Vec<String>,
+    #[builder(default, default = Self::default_cwd)]
+    cwd: String,
+    #[builder(each = "var")]
+    env: u64,
+    #[builder(missing_message = "never shown")]
+    timeout: Option<u64>,
+}
+
+fn main() {}
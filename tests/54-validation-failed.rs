@@ -0,0 +1,69 @@
+// Struct-level validation failures are reported as `ValidationFailed` rather
+// than `InvalidState`, so callers can tell them apart from missing fields
+// without parsing messages. Cross-field validations list the fields they
+// checked, and struct validators can build the error themselves with
+// `validation_failed(...)` and `with_fields(...)`. The variant's message can
+// be customized with #[builder(error_display(validation_failed = "..."))].
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(cross_validate(fields(min, max), with = ordered))]
+#[builder(validate = fits_budget)]
+#[builder(error_display(validation_failed = "rejected pool ({fields:?}): {message}"))]
+pub struct Pool {
+    min: u32,
+    max: u32,
+    cost_per_instance: u32,
+}
+
+fn ordered(min: &u32, max: &u32) -> Result<(), String> {
+    if min > max {
+        return Err(format!("{min} is larger than {max}"));
+    }
+
+    Ok(())
+}
+
+fn fits_budget(pool: Pool) -> Result<Pool, PoolBuilderError> {
+    if pool.max * pool.cost_per_instance > 100 {
+        return Err(PoolBuilderError::validation_failed("over budget")
+            .with_fields(["max", "cost_per_instance"])
+            .with_code("over_budget"));
+    }
+
+    Ok(pool)
+}
+
+fn main() {
+    let err = Pool::builder()
+        .min(4_u32)
+        .max(2_u32)
+        .cost_per_instance(1_u32)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        PoolBuilderError::invalid_fields(&["min", "max"], "4 is larger than 2")
+    );
+    assert!(matches!(
+        &err,
+        PoolBuilderError::ValidationFailed { fields, .. } if fields == &["min", "max"]
+    ));
+    assert_eq!(err.error_code(), "invalid_fields");
+
+    let err = Pool::builder()
+        .min(1_u32)
+        .max(20_u32)
+        .cost_per_instance(10_u32)
+        .build()
+        .unwrap_err();
+    assert_eq!(err.error_code(), "over_budget");
+    assert_eq!(
+        err.to_string(),
+        r#"rejected pool (["max", "cost_per_instance"]): over budget"#
+    );
+
+    let err = Pool::builder().min(1_u32).build().unwrap_err();
+    assert!(matches!(err, PoolBuilderError::InvalidState { .. }));
+}
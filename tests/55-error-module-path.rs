@@ -0,0 +1,50 @@
+// #[builder(error_module_path)] names the target in error messages by its full
+// path, e.g. `my_app::billing::Invoice` instead of `Invoice`, so logs tell
+// apart structs which share a name across modules. Custom formats given to
+// #[builder(error_display(...))] can refer to the name as `{target}`.
+
+mod billing {
+    use turann::Builder;
+
+    #[derive(Debug, Builder)]
+    #[builder(error_module_path)]
+    pub struct Invoice {
+        pub amount: u64,
+    }
+
+    #[derive(Debug, Builder)]
+    #[builder(error_module_path, error_derive(thiserror::Error))]
+    #[builder(error_display(invalid_state = "{target} is incomplete ({message})"))]
+    pub struct Receipt {
+        pub invoice: u64,
+    }
+}
+
+mod shipping {
+    use turann::Builder;
+
+    #[derive(Debug, Builder)]
+    pub struct Invoice {
+        pub weight: u64,
+    }
+}
+
+fn main() {
+    let err = billing::Invoice::builder().build().unwrap_err();
+    let message = err.to_string();
+    assert!(message.starts_with("Unable to build "));
+    assert!(message.ends_with("::billing::Invoice: missing required field(s): `amount: u64`"));
+
+    let err = billing::Receipt::builder().build().unwrap_err();
+    assert!(
+        err.to_string().ends_with(
+            "::billing::Receipt is incomplete (missing required field(s): `invoice: u64`)"
+        )
+    );
+
+    let err = shipping::Invoice::builder().build().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unable to build Invoice: missing required field(s): `weight: u64`"
+    );
+}
@@ -0,0 +1,62 @@
+// #[builder(error_into(Target = path, ...))] generates a
+// `From<{Struct}BuilderError>` impl for each listed type, so `?` converts
+// builder errors into application errors without hand-written impls. `path`
+// is a `fn({Struct}BuilderError) -> Target`. Without `= path`, the target is
+// built from the error message through its `From<String>` impl.
+
+use turann::Builder;
+
+#[derive(Debug, PartialEq)]
+pub enum ServiceError {
+    Config { code: &'static str, message: String },
+}
+
+impl ServiceError {
+    fn from_builder(error: ConnectionBuilderError) -> Self {
+        ServiceError::Config {
+            code: error.error_code(),
+            message: error.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ApiError(String);
+
+impl From<String> for ApiError {
+    fn from(message: String) -> Self {
+        ApiError(message)
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(error_into(ServiceError = ServiceError::from_builder, ApiError))]
+pub struct Connection {
+    host: String,
+}
+
+fn connect() -> Result<Connection, ServiceError> {
+    Ok(Connection::builder().build()?)
+}
+
+fn handle() -> Result<Connection, ApiError> {
+    Ok(Connection::builder().build()?)
+}
+
+fn main() {
+    assert_eq!(
+        connect().unwrap_err(),
+        ServiceError::Config {
+            code: "missing_fields",
+            message: "Unable to build Connection: missing required field(s): `host: String`"
+                .to_owned(),
+        }
+    );
+
+    assert_eq!(
+        handle().unwrap_err(),
+        ApiError(
+            "Unable to build Connection: missing required field(s): `host: String`".to_owned()
+        )
+    );
+}
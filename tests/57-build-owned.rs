@@ -0,0 +1,27 @@
+// `build_owned()` consumes the builder and, when the build fails, returns it
+// together with the error. Callers can fix the offending fields and retry
+// without cloning the builder before every attempt.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Job {
+    name: String,
+    #[builder(range = 1..=10)]
+    retries: u8,
+}
+
+fn main() {
+    let mut builder = Job::builder();
+    builder.name("nightly-backup");
+
+    let (mut builder, err) = builder.build_owned().unwrap_err();
+    assert_eq!(err, JobBuilderError::missing_field("retries: u8"));
+    assert_eq!(builder.get_name().map(String::as_str), Some("nightly-backup"));
+
+    builder.retries(3_u8).unwrap();
+
+    let job = builder.build_owned().unwrap();
+    assert_eq!(job.name, "nightly-backup");
+    assert_eq!(job.retries, 3);
+}
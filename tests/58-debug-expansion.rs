@@ -0,0 +1,65 @@
+// #[builder(debug_expansion)] writes the code generated for the struct to
+// `target/turann-expansions/{Struct}-{hash}.rs` while compiling, so the output
+// of the derive can be inspected without extra tooling. The hash comes from
+// where the struct is declared, so same-named structs in different modules do
+// not overwrite each other. The code is pretty-printed with the
+// `debug-expansion` feature, and written as raw tokens without it.
+// `CARGO_TARGET_DIR` is respected when set.
+
+use std::path::{Path, PathBuf};
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(debug_expansion)]
+pub struct Task {
+    name: String,
+}
+
+mod scheduled {
+    use turann::Builder;
+
+    #[derive(Debug, Builder)]
+    #[builder(debug_expansion)]
+    pub struct Task {
+        pub name: String,
+        pub cron: String,
+    }
+}
+
+fn main() {
+    let target_dir = option_env!("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("target"));
+
+    let expansions = std::fs::read_dir(target_dir.join("turann-expansions"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let file_name = path.file_name().unwrap().to_str().unwrap();
+            file_name.starts_with("Task-") && file_name.ends_with(".rs")
+        })
+        .map(|path| std::fs::read_to_string(path).unwrap())
+        .filter(|expansion| expansion.contains("TaskBuilder") && expansion.contains("fn build"))
+        .collect::<Vec<_>>();
+
+    assert!(
+        expansions
+            .iter()
+            .any(|expansion| expansion.contains("cron"))
+    );
+    assert!(
+        expansions
+            .iter()
+            .any(|expansion| !expansion.contains("cron"))
+    );
+
+    let task = Task::builder().name("compile").build().unwrap();
+    assert_eq!(task.name, "compile");
+
+    let task = scheduled::Task::builder()
+        .name("backup")
+        .cron("0 3 * * *")
+        .build()
+        .unwrap();
+    assert_eq!(task.cron, "0 3 * * *");
+}
@@ -0,0 +1,17 @@
+// `build()` clones the stored value of every field, so their types must
+// implement `Clone`. When one does not, the only error points at the field
+// and explains the requirement instead of pointing inside the generated code:
+// the generated impls which clone the values just do not apply. This is a
+// compile_fail test.
+
+use turann::Builder;
+
+pub struct Handle(u8);
+
+#[derive(Builder)]
+pub struct Worker {
+    name: String,
+    handle: Handle,
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+// Struct-level validators with the wrong signature are reported at the
+// attribute naming them as well. `validate` expects a `fn(T) -> Result<T, E>`
+// and `pre_validate` a `fn(&Builder) -> Result<(), E>`, while `cross_validate`
+// takes a reference to each named field. This is a compile_fail test.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(validate = has_name, pre_validate = AccountBuilder::ready)]
+#[builder(cross_validate(fields(username, age), with = old_enough))]
+pub struct Account {
+    username: String,
+    age: u8,
+}
+
+fn has_name(account: &Account) -> bool {
+    !account.username.is_empty()
+}
+
+fn old_enough(_username: &str, age: u8) -> Result<(), String> {
+    if age < 18 {
+        return Err("too young".to_owned());
+    }
+
+    Ok(())
+}
+
+impl AccountBuilder {
+    fn ready(self) -> bool {
+        true
+    }
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+// A malformed item inside `#[builder(...)]` no longer hides the ones after it.
+// Every problem in the derive, on the struct and on each field, is reported
+// in the same compile. This is a compile_fail test.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(validate = 42, unknown_option, compact)]
+pub struct Server {
+    #[builder(each = 1, default, default)]
+    hosts: Vec<String>,
+    #[builder(range = , clearable, cheque)]
+    port: Option<u16>,
+}
+
+fn main() {}
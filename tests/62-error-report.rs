@@ -0,0 +1,80 @@
+// `to_report()` flattens a builder error into one entry per failure, each
+// with the field it belongs to, its kind, its `error_code()`, and its message.
+// The shape stays the same however the errors were grouped, so it can be
+// returned to clients as is.
+
+use std::borrow::Cow;
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(cross_validate(fields(min_workers, max_workers), with = ordered))]
+pub struct Pool {
+    #[builder(range = 1..=65535)]
+    port: u16,
+    host: String,
+    min_workers: u8,
+    max_workers: u8,
+}
+
+fn ordered(min: &u8, max: &u8) -> Result<(), String> {
+    if min > max {
+        return Err("min_workers exceeds max_workers".to_owned());
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let err = Pool::builder().port(0u16).unwrap_err();
+    assert_eq!(
+        err.to_report(),
+        PoolBuilderErrorReport {
+            errors: vec![PoolBuilderErrorReportEntry {
+                field: Some(Cow::Borrowed("port")),
+                kind: "invalid_field",
+                code: err.error_code(),
+                message: Cow::Borrowed("must be in range 1..=65535"),
+            }],
+        }
+    );
+
+    let err = Pool::builder()
+        .port(8080u16)
+        .unwrap()
+        .host("localhost".to_owned())
+        .min_workers(4)
+        .max_workers(2)
+        .build()
+        .unwrap_err();
+    let report = err.to_report();
+    let fields = report
+        .errors
+        .iter()
+        .map(|entry| entry.field.as_deref())
+        .collect::<Vec<_>>();
+    assert_eq!(fields, vec![Some("min_workers"), Some("max_workers")]);
+    assert!(
+        report
+            .errors
+            .iter()
+            .all(|entry| entry.kind == "validation_failed")
+    );
+
+    let err = PoolBuilderError::multiple([
+        PoolBuilderError::missing_field("host"),
+        PoolBuilderError::invalid_field("port", "taken"),
+    ]);
+    let kinds = err
+        .to_report()
+        .errors
+        .into_iter()
+        .map(|entry| (entry.field, entry.kind))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![
+            (None, "invalid_state"),
+            (Some(Cow::Borrowed("port")), "invalid_field"),
+        ]
+    );
+}
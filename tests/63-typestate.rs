@@ -0,0 +1,53 @@
+// #[builder(typestate)] makes `builder()` return a typestate builder which
+// tracks the required fields in its type. Its setters take the builder by
+// value and `build()` only exists once every required field is set, so a
+// missing field is a compile error rather than a runtime one. Optional and
+// defaulted fields can be set in any state, and validations still run. The
+// runtime-checked builder remains available through `into_builder()`.
+
+use turann::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(typestate)]
+pub struct Server {
+    host: String,
+    #[builder(range = 1..=65535)]
+    port: u16,
+    #[builder(each = "alias")]
+    aliases: Vec<String>,
+    #[builder(default)]
+    workers: u8,
+    motd: Option<String>,
+}
+
+fn main() {
+    let server = Server::builder()
+        .motd("hello".to_owned())
+        .port(8080u16)
+        .unwrap()
+        .alias("www".to_owned())
+        .host("localhost".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        server,
+        Server {
+            host: "localhost".to_owned(),
+            port: 8080,
+            aliases: vec!["www".to_owned()],
+            workers: 0,
+            motd: Some("hello".to_owned()),
+        }
+    );
+
+    let err = Server::builder().port(0u16).unwrap_err();
+    assert_eq!(err.error_code(), "out_of_range");
+
+    let mut builder = Server::builder()
+        .host("localhost".to_owned())
+        .into_builder();
+    assert!(builder.build().is_err());
+    builder.port(443u16).unwrap();
+    assert_eq!(builder.build().unwrap().port, 443);
+}
@@ -0,0 +1,15 @@
+// With #[builder(typestate)], calling `build()` before every required field is
+// set fails to compile. This is a compile_fail test.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(typestate)]
+pub struct Server {
+    host: String,
+    port: u16,
+}
+
+fn main() {
+    let _ = Server::builder().host("localhost".to_owned()).build();
+}
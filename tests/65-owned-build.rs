@@ -0,0 +1,68 @@
+// #[builder(build = "owned")] makes `build()` take the builder by value and
+// move the stored values into the target instead of cloning them, which
+// matters for large `Vec` or `String` fields. Validation runs as before.
+// `build_owned()` still hands the builder back on failure.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use turann::Builder;
+
+static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, PartialEq)]
+pub struct Payload(Vec<u8>);
+
+impl Clone for Payload {
+    fn clone(&self) -> Self {
+        CLONES.fetch_add(1, Ordering::SeqCst);
+        Payload(self.0.clone())
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build = "owned", pre_validate = UploadBuilder::named)]
+pub struct Upload {
+    name: String,
+    payload: Payload,
+    #[builder(each = "chunk")]
+    chunks: Vec<Payload>,
+    #[builder(default)]
+    retries: u8,
+    checksum: Option<Payload>,
+}
+
+impl UploadBuilder {
+    fn named(&self) -> Result<(), UploadBuilderError> {
+        match self.get_name() {
+            Some(name) if name.is_empty() => {
+                Err(UploadBuilderError::invalid_field("name", "cannot be empty"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn main() {
+    let mut builder = Upload::builder();
+    builder
+        .name("backup.tar".to_owned())
+        .payload(Payload(vec![1, 2, 3]))
+        .chunk(Payload(vec![1]))
+        .checksum(Payload(vec![6]));
+
+    let upload = builder.build().unwrap();
+    assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+    assert_eq!(upload.payload, Payload(vec![1, 2, 3]));
+    assert_eq!(upload.chunks, vec![Payload(vec![1])]);
+    assert_eq!(upload.retries, 0);
+    assert_eq!(upload.checksum, Some(Payload(vec![6])));
+
+    let mut builder = Upload::builder();
+    builder.name(String::new()).payload(Payload(vec![]));
+    assert!(builder.build().is_err());
+
+    let mut builder = Upload::builder();
+    builder.name("backup.tar".to_owned());
+    let (mut builder, _) = builder.build_owned().unwrap_err();
+    builder.payload(Payload(vec![4]));
+    assert_eq!(builder.build_owned().unwrap().payload, Payload(vec![4]));
+}
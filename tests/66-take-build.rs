@@ -0,0 +1,101 @@
+// #[builder(build = "take")] makes `build()` take `&mut self` and move each
+// stored value out of its slot, so field types no longer need to implement
+// `Clone`. The builder itself is no longer `Clone` either, and it is left
+// empty after a successful build. A build which fails the checks that run
+// before the target is constructed (missing fields, field checks, and
+// pre-validators) leaves the values in place. Cross-field validations,
+// `use_validator`, `use_garde`, and struct validators run on the constructed
+// target, so the values were already moved out when they fail and the builder
+// is left empty.
+
+use std::sync::mpsc::{self, Receiver};
+use turann::Builder;
+
+#[derive(Debug, Default)]
+pub struct Connection {
+    id: u32,
+}
+
+#[derive(Debug, Builder)]
+#[builder(build = "take", pre_validate = WorkerBuilder::ready)]
+pub struct Worker {
+    name: String,
+    jobs: Receiver<String>,
+    #[builder(default)]
+    connection: Connection,
+    #[builder(each = "spare")]
+    spares: Vec<Connection>,
+    log: Option<std::fs::File>,
+}
+
+impl WorkerBuilder {
+    fn ready(&self) -> Result<(), WorkerBuilderError> {
+        match self.get_name() {
+            Some(name) if name.is_empty() => {
+                Err(WorkerBuilderError::invalid_field("name", "cannot be empty"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build = "take", typestate)]
+pub struct Listener {
+    jobs: Receiver<String>,
+}
+
+#[derive(Debug, Builder)]
+#[builder(build = "take", cross_validate(fields(min, max), with = ordered))]
+pub struct Pool {
+    min: u32,
+    max: u32,
+}
+
+fn ordered(min: &u32, max: &u32) -> Result<(), String> {
+    if min > max {
+        return Err(format!("{min} is greater than {max}"));
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let (sender, receiver) = mpsc::channel();
+
+    let mut builder = Worker::builder();
+    builder
+        .name(String::new())
+        .jobs(receiver)
+        .spare(Connection { id: 7 });
+
+    assert!(builder.build().is_err());
+    assert!(builder.has_jobs());
+
+    builder.name("indexer".to_owned());
+    let worker = builder.build().unwrap();
+
+    sender.send("reindex".to_owned()).unwrap();
+    assert_eq!(worker.jobs.recv().unwrap(), "reindex");
+    assert_eq!(worker.name, "indexer");
+    assert_eq!(worker.connection.id, 0);
+    assert_eq!(worker.spares[0].id, 7);
+    assert!(worker.log.is_none());
+
+    assert!(!builder.has_jobs());
+    assert!(builder.build().is_err());
+
+    let (sender, receiver) = mpsc::channel();
+    let listener = Listener::builder().jobs(receiver).build().unwrap();
+
+    sender.send("ping".to_owned()).unwrap();
+    assert_eq!(listener.jobs.recv().unwrap(), "ping");
+
+    // a failing cross-field validation ran on the taken values
+    let mut builder = Pool::builder();
+    builder.min(8_u32).max(4_u32);
+
+    assert!(builder.build().is_err());
+    assert!(!builder.has_min());
+    assert!(!builder.has_max());
+}
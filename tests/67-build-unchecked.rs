@@ -0,0 +1,49 @@
+// #[builder(unchecked)] adds `build_unchecked()`, which skips every validator
+// and the missing-field check and returns the target directly. It is meant
+// for hot paths where the input was already validated. Finalizers still run,
+// and a required field which was never set makes it panic.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(unchecked, validate = Order::positive, finalize = Order::rounded)]
+pub struct Order {
+    sku: String,
+    quantity: i32,
+    #[builder(default)]
+    note: String,
+}
+
+impl Order {
+    fn positive(self) -> Result<Self, OrderBuilderError> {
+        if self.quantity <= 0 {
+            return Err(OrderBuilderError::invalid_field(
+                "quantity",
+                "must be positive",
+            ));
+        }
+
+        Ok(self)
+    }
+
+    fn rounded(mut self) -> Self {
+        self.quantity -= self.quantity % 10;
+        self
+    }
+}
+
+fn main() {
+    let mut builder = Order::builder();
+    builder.sku("tea".to_owned()).quantity(-25);
+
+    assert!(builder.build().is_err());
+
+    let order = builder.build_unchecked();
+    assert_eq!(order.sku, "tea");
+    assert_eq!(order.quantity, -20);
+    assert_eq!(order.note, "");
+
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = std::panic::catch_unwind(|| Order::builder().build_unchecked());
+    assert!(result.is_err());
+}
@@ -0,0 +1,36 @@
+// `build_or_panic()` and `expect_build(message)` build the target or panic
+// with the formatted error, listing every problem on its own line. They cut
+// down on `.unwrap()` noise in test fixtures.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Fixture {
+    name: String,
+    #[builder(default)]
+    seed: u64,
+}
+
+fn panic_message(build: impl FnOnce() -> Fixture + std::panic::UnwindSafe) -> String {
+    let payload = std::panic::catch_unwind(build).unwrap_err();
+
+    payload.downcast_ref::<String>().unwrap().clone()
+}
+
+fn main() {
+    let mut builder = Fixture::builder();
+    builder.name("users".to_owned());
+    assert_eq!(builder.build_or_panic().name, "users");
+    assert_eq!(builder.expect_build("fixture").seed, 0);
+
+    std::panic::set_hook(Box::new(|_| {}));
+
+    assert_eq!(
+        panic_message(|| Fixture::builder().build_or_panic()),
+        "Unable to build Fixture: missing required field(s): `name: String`"
+    );
+    assert_eq!(
+        panic_message(|| Fixture::builder().expect_build("users fixture")),
+        "users fixture: Unable to build Fixture: missing required field(s): `name: String`"
+    );
+}
@@ -0,0 +1,70 @@
+// When nothing can make the build fail, because every field is an `Option`,
+// an `each` collection, or has a default, and no build-time validation is
+// declared, `build()` returns the target directly instead of a `Result`.
+// Setter validation does not count, it fails at the setter. Helpers built on
+// top of `build()` keep their signatures, in every build mode.
+
+use turann::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(finalize = Settings::normalized)]
+pub struct Settings {
+    #[builder(range = 1..=64)]
+    threads: Option<u8>,
+    #[builder(each = "include")]
+    includes: Vec<String>,
+    #[builder(default)]
+    verbose: bool,
+}
+
+impl Settings {
+    fn normalized(mut self) -> Self {
+        self.includes.sort();
+        self
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(build = "owned")]
+pub struct Limits {
+    max_connections: Option<u32>,
+}
+
+#[derive(Debug, Builder)]
+pub struct Account {
+    name: Option<String>,
+    #[builder(each = "role", non_empty)]
+    roles: Vec<String>,
+}
+
+fn main() {
+    let mut builder = Settings::builder();
+    builder
+        .threads(4u8)
+        .unwrap()
+        .include("src".to_owned())
+        .include("examples".to_owned());
+
+    let settings: Settings = builder.build();
+    assert_eq!(
+        settings,
+        Settings {
+            threads: Some(4),
+            includes: vec!["examples".to_owned(), "src".to_owned()],
+            verbose: false,
+        }
+    );
+
+    assert_eq!(Settings::builder().build_or_panic().threads, None);
+    assert!(Settings::builder().build_owned().is_ok());
+
+    // an owned build consumes the builder, which it never has to hand back
+    let mut builder = Limits::builder();
+    builder.max_connections(16u32);
+    let limits: Limits = builder.clone().build();
+    assert_eq!(limits.max_connections, Some(16));
+    assert_eq!(builder.build_owned().unwrap().max_connections, Some(16));
+
+    // a collection check at build time can still fail
+    assert!(Account::builder().build().is_err());
+}
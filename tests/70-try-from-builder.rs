@@ -0,0 +1,55 @@
+// The target implements `TryFrom` for its builder, delegating to `build()`,
+// so generic code can consume builders through `TryFrom` and `TryInto`. When
+// the build cannot fail, `From` is implemented instead, which brings
+// `TryFrom` along with it and lets APIs take `impl Into<Target>`.
+
+use std::convert::Infallible;
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Endpoint {
+    url: String,
+    #[builder(default)]
+    timeout_secs: u32,
+}
+
+#[derive(Debug, Builder)]
+#[builder(build = "take")]
+pub struct Flags {
+    verbose: Option<bool>,
+}
+
+fn convert<B, T>(builder: B) -> Result<T, T::Error>
+where
+    T: TryFrom<B>,
+{
+    T::try_from(builder)
+}
+
+fn run(flags: impl Into<Flags>) -> bool {
+    flags.into().verbose.unwrap_or(false)
+}
+
+fn main() {
+    let mut builder = Endpoint::builder();
+    builder.url("https://example.com".to_owned());
+
+    let endpoint: Endpoint = builder.clone().try_into().unwrap();
+    assert_eq!(endpoint.url, "https://example.com");
+
+    let err = convert::<_, Endpoint>(Endpoint::builder()).unwrap_err();
+    assert_eq!(err.error_code(), "missing_fields");
+
+    let mut builder = Flags::builder();
+    builder.verbose(true);
+
+    let flags = Flags::from(builder);
+    assert_eq!(flags.verbose, Some(true));
+
+    let flags: Result<Flags, Infallible> = convert(Flags::builder());
+    assert_eq!(flags.unwrap().verbose, None);
+
+    let mut builder = Flags::builder();
+    builder.verbose(true);
+    assert!(run(builder));
+}
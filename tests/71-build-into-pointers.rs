@@ -0,0 +1,40 @@
+// #[builder(build_into(arc, rc, boxed))] adds `build_arc()`, `build_rc()`, and
+// `build_boxed()`, which build the target straight into the smart pointer,
+// for large structs which are always shared. They fail the same way `build()`
+// does, and return the pointer directly when the build cannot fail.
+
+use std::{rc::Rc, sync::Arc};
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(build_into(arc, rc, boxed))]
+pub struct Catalog {
+    name: String,
+    #[builder(each = "item")]
+    items: Vec<String>,
+}
+
+#[derive(Debug, Builder)]
+#[builder(build_into(arc))]
+pub struct Theme {
+    accent: Option<String>,
+}
+
+fn main() {
+    let mut builder = Catalog::builder();
+    builder.name("books".to_owned()).item("dune".to_owned());
+
+    let shared: Arc<Catalog> = builder.build_arc().unwrap();
+    assert_eq!(shared.name, "books");
+
+    let local: Rc<Catalog> = builder.build_rc().unwrap();
+    assert_eq!(local.items, vec!["dune".to_owned()]);
+
+    let boxed: Box<Catalog> = builder.build_boxed().unwrap();
+    assert_eq!(boxed.items.len(), 1);
+
+    assert!(Catalog::builder().build_arc().is_err());
+
+    let theme: Arc<Theme> = Theme::builder().build_arc();
+    assert!(theme.accent.is_none());
+}
@@ -0,0 +1,78 @@
+// #[builder(base)] adds `build_with_base(base)`, which fills every field that
+// was never set from an existing value instead of reporting it as missing,
+// like struct update syntax. Struct-level validation still runs on the
+// result.
+
+use turann::Builder;
+
+#[derive(Clone, Debug, PartialEq, Builder)]
+#[builder(base, validate = Config::consistent)]
+pub struct Config {
+    host: String,
+    port: u16,
+    #[builder(default)]
+    retries: u8,
+    proxy: Option<String>,
+    #[builder(each = "feature")]
+    features: Vec<String>,
+}
+
+impl Config {
+    fn consistent(self) -> Result<Self, ConfigBuilderError> {
+        if self.port == 0 {
+            return Err(ConfigBuilderError::invalid_field("port", "cannot be zero"));
+        }
+
+        Ok(self)
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(base, build = "owned")]
+pub struct Label {
+    text: String,
+    color: Option<String>,
+}
+
+fn main() {
+    let production = Config {
+        host: "example.com".to_owned(),
+        port: 443,
+        retries: 3,
+        proxy: Some("proxy.internal".to_owned()),
+        features: vec!["tls".to_owned()],
+    };
+
+    let mut builder = Config::builder();
+    builder.host("staging.example.com".to_owned()).retries(0);
+
+    let staging = builder.build_with_base(&production).unwrap();
+    assert_eq!(
+        staging,
+        Config {
+            host: "staging.example.com".to_owned(),
+            port: 443,
+            retries: 0,
+            proxy: Some("proxy.internal".to_owned()),
+            features: vec!["tls".to_owned()],
+        }
+    );
+
+    // the builder itself is left untouched
+    assert!(builder.build().is_err());
+
+    let mut builder = Config::builder();
+    builder.port(0u16).feature("http2".to_owned());
+    assert!(builder.build_with_base(&production).is_err());
+
+    let base = Label {
+        text: "draft".to_owned(),
+        color: Some("grey".to_owned()),
+    };
+    let mut builder = Label::builder();
+    builder.color("red".to_owned());
+
+    let label = builder.build_with_base(&base).unwrap();
+    assert_eq!(label.text, "draft");
+    assert_eq!(label.color.as_deref(), Some("red"));
+}
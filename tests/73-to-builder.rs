@@ -0,0 +1,39 @@
+// `to_builder()` creates a builder with every field set from an existing
+// value, for the read-modify-rebuild workflow on immutable domain objects.
+// The rebuilt value goes through validation again.
+
+use turann::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+pub struct User {
+    name: String,
+    #[builder(range = 13..=130)]
+    age: u8,
+    #[builder(default)]
+    admin: bool,
+    email: Option<String>,
+    #[builder(each = "group")]
+    groups: Vec<String>,
+}
+
+fn main() {
+    let mut builder = User::builder();
+    builder
+        .name("ada".to_owned())
+        .age(36u8)
+        .unwrap()
+        .group("staff".to_owned());
+    let user = builder.build().unwrap();
+
+    let mut builder = user.to_builder();
+    assert_eq!(builder.get_name().map(String::as_str), Some("ada"));
+    assert_eq!(builder.build().unwrap(), user);
+
+    builder.admin(true).group("ops".to_owned());
+    let promoted = builder.build().unwrap();
+    assert!(promoted.admin);
+    assert_eq!(promoted.groups, vec!["staff".to_owned(), "ops".to_owned()]);
+    assert_eq!(promoted.email, None);
+
+    assert!(user.to_builder().age(7u8).is_err());
+}
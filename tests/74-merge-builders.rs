@@ -0,0 +1,50 @@
+// `merge(other)` overwrites every field which is set in `other`, leaving the
+// rest alone. Layered configuration, such as defaults, then a file, then the
+// environment, then the command line, becomes one call per layer.
+
+use turann::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+pub struct Config {
+    host: String,
+    port: u16,
+    #[builder(default)]
+    verbose: bool,
+    log_file: Option<String>,
+    #[builder(each = "plugin")]
+    plugins: Vec<String>,
+}
+
+fn main() {
+    let mut defaults = Config::builder();
+    defaults
+        .host("localhost".to_owned())
+        .port(8080u16)
+        .plugin("metrics".to_owned());
+
+    let mut file = Config::builder();
+    file.port(9090u16).log_file("/var/log/app.log".to_owned());
+
+    let mut cli = Config::builder();
+    cli.verbose(true);
+
+    let config = defaults.merge(&file).merge(&cli).build().unwrap();
+    assert_eq!(
+        config,
+        Config {
+            host: "localhost".to_owned(),
+            port: 9090,
+            verbose: true,
+            log_file: Some("/var/log/app.log".to_owned()),
+            plugins: vec!["metrics".to_owned()],
+        }
+    );
+
+    let mut env = Config::builder();
+    env.plugin("tracing".to_owned());
+    defaults.merge(&env);
+    assert_eq!(
+        defaults.build().unwrap().plugins,
+        vec!["tracing".to_owned()]
+    );
+}
@@ -0,0 +1,55 @@
+// `#[builder(diff)]` adds `diff(&target)`, listing the fields which are set in
+// the builder to a different value than in an existing instance. It makes
+// audit logs and "nothing changed" checks on updates cheap. Comparing needs
+// `PartialEq` on every field, hence the opt-in.
+
+use turann::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(diff)]
+pub struct Account {
+    name: String,
+    #[builder(default)]
+    active: bool,
+    email: Option<String>,
+    #[builder(each = "role")]
+    roles: Vec<String>,
+}
+
+fn main() {
+    let account = Account {
+        name: "alice".to_owned(),
+        active: true,
+        email: None,
+        roles: vec!["admin".to_owned()],
+    };
+
+    // unset fields are never reported
+    assert!(Account::builder().diff(&account).is_empty());
+
+    // setting a field to its current value is a no-op update
+    let mut update = Account::builder();
+    update.name("alice".to_owned()).role("admin".to_owned());
+    assert!(update.diff(&account).is_empty());
+
+    let mut update = Account::builder();
+    update
+        .name("alice".to_owned())
+        .active(false)
+        .email("alice@example.com".to_owned());
+    assert_eq!(
+        update.diff(&account),
+        vec![
+            AccountBuilderFieldChange {
+                field: "active",
+                from: "true".to_owned(),
+                to: "false".to_owned(),
+            },
+            AccountBuilderFieldChange {
+                field: "email",
+                from: "None".to_owned(),
+                to: "Some(\"alice@example.com\")".to_owned(),
+            },
+        ]
+    );
+}
@@ -0,0 +1,48 @@
+// `#[builder(checkpoint)]` adds `checkpoint()` and `rollback()`, saving and
+// restoring the builder's state on an internal stack. A multi-step form can
+// take a checkpoint before each step and undo it without cloning builders by
+// hand.
+
+use turann::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(checkpoint)]
+pub struct Order {
+    item: String,
+    #[builder(default)]
+    quantity: u32,
+    note: Option<String>,
+}
+
+fn main() {
+    let mut builder = Order::builder();
+    builder.item("book".to_owned());
+
+    builder.checkpoint().quantity(2u32);
+    builder.checkpoint().quantity(5u32).note("gift".to_owned());
+
+    // undoes the last step only
+    assert!(builder.rollback());
+    assert_eq!(
+        builder.build().unwrap(),
+        Order {
+            item: "book".to_owned(),
+            quantity: 2,
+            note: None,
+        }
+    );
+
+    assert!(builder.rollback());
+    assert_eq!(
+        builder.build().unwrap(),
+        Order {
+            item: "book".to_owned(),
+            quantity: 0,
+            note: None,
+        }
+    );
+
+    // nothing left to undo
+    assert!(!builder.rollback());
+    assert!(builder.build().is_ok());
+}
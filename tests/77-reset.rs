@@ -0,0 +1,54 @@
+// `reset()` unsets every field, so one builder can be reused, for example
+// across the iterations of a loop, without allocating a new one each time.
+
+use turann::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+pub struct Row {
+    id: u64,
+    #[builder(default)]
+    label: String,
+    comment: Option<String>,
+    #[builder(each = "tag")]
+    tags: Vec<String>,
+}
+
+fn main() {
+    let mut builder = Row::builder();
+    let mut rows = vec![];
+
+    for id in 0..3u64 {
+        builder.reset().id(id);
+        if id == 0 {
+            builder
+                .label("first".to_owned())
+                .comment("header".to_owned())
+                .tag("bold".to_owned());
+        }
+
+        rows.push(builder.build().unwrap());
+    }
+
+    assert_eq!(
+        rows[0],
+        Row {
+            id: 0,
+            label: "first".to_owned(),
+            comment: Some("header".to_owned()),
+            tags: vec!["bold".to_owned()],
+        }
+    );
+    // nothing set for the first row leaks into the later ones
+    assert_eq!(
+        rows[2],
+        Row {
+            id: 2,
+            label: String::new(),
+            comment: None,
+            tags: vec![],
+        }
+    );
+
+    // a reset builder is missing its required fields again
+    assert!(builder.reset().build().is_err());
+}
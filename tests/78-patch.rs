@@ -0,0 +1,61 @@
+// `#[builder(patch)]` also generates `{Struct}Patch`, with every field
+// optional, and `build_patch()`, which collects the fields that were set and
+// never fails. `apply()` writes them onto a stored value, leaving the rest
+// unchanged, which is how partial updates usually reach an entity.
+
+use turann::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(patch)]
+pub struct User {
+    name: String,
+    #[builder(default)]
+    admin: bool,
+    email: Option<String>,
+    #[builder(each = "group")]
+    groups: Vec<String>,
+}
+
+fn main() {
+    let mut user = User {
+        name: "alice".to_owned(),
+        admin: true,
+        email: Some("alice@example.com".to_owned()),
+        groups: vec!["staff".to_owned()],
+    };
+
+    // the required `name` may be left out of a patch
+    let mut update = User::builder();
+    update.admin(false);
+    let patch: UserPatch = update.build_patch();
+    assert_eq!(patch.name, None);
+    assert_eq!(patch.admin, Some(false));
+    assert_eq!(patch.groups, None);
+
+    patch.apply(&mut user);
+    assert_eq!(
+        user,
+        User {
+            name: "alice".to_owned(),
+            admin: false,
+            email: Some("alice@example.com".to_owned()),
+            groups: vec!["staff".to_owned()],
+        }
+    );
+
+    let mut update = User::builder();
+    update
+        .name("bob".to_owned())
+        .group("ops".to_owned())
+        .group("dev".to_owned());
+    update.build_patch().apply(&mut user);
+    assert_eq!(
+        user,
+        User {
+            name: "bob".to_owned(),
+            admin: false,
+            email: Some("alice@example.com".to_owned()),
+            groups: vec!["ops".to_owned(), "dev".to_owned()],
+        }
+    );
+}
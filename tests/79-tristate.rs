@@ -0,0 +1,84 @@
+// `#[builder(tristate)]` on an `Option` field tells "leave it alone" apart
+// from "set it to null", which an `Option` alone cannot. Besides `x(value)`,
+// the builder gets `x_null()` and `x_unset()`, and a patch only clears the
+// target's field after `x_null()`.
+
+use turann::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(patch)]
+pub struct Profile {
+    name: String,
+    #[builder(tristate)]
+    nickname: Option<String>,
+    #[builder(tristate)]
+    avatar: Option<String>,
+}
+
+fn main() {
+    let mut builder = Profile::builder();
+    builder.name("alice".to_owned()).nickname("ali".to_owned());
+    assert!(builder.has_nickname());
+    assert!(!builder.has_avatar());
+    assert_eq!(builder.get_nickname(), Some(&"ali".to_owned()));
+
+    // a null field builds to `None`, like an unset one
+    builder.avatar_null();
+    assert!(builder.has_avatar());
+    assert_eq!(builder.avatar, ProfileBuilderTristate::Null);
+    assert_eq!(
+        builder.build().unwrap(),
+        Profile {
+            name: "alice".to_owned(),
+            nickname: Some("ali".to_owned()),
+            avatar: None,
+        }
+    );
+
+    builder.nickname_unset();
+    assert!(!builder.has_nickname());
+
+    let mut profile = Profile {
+        name: "alice".to_owned(),
+        nickname: Some("ali".to_owned()),
+        avatar: Some("cat.png".to_owned()),
+    };
+
+    // clears the avatar, keeps the nickname
+    let mut update = Profile::builder();
+    update.avatar_null();
+    update.build_patch().apply(&mut profile);
+    assert_eq!(
+        profile,
+        Profile {
+            name: "alice".to_owned(),
+            nickname: Some("ali".to_owned()),
+            avatar: None,
+        }
+    );
+
+    let mut update = Profile::builder();
+    update
+        .nickname("al".to_owned())
+        .avatar("dog.png".to_owned());
+    let patch = update.build_patch();
+    assert_eq!(
+        patch.nickname,
+        ProfileBuilderTristate::Value("al".to_owned())
+    );
+    assert_eq!(patch.name, None);
+
+    patch.apply(&mut profile);
+    assert_eq!(profile.nickname, Some("al".to_owned()));
+    assert_eq!(profile.avatar, Some("dog.png".to_owned()));
+
+    // a builder seeded from a value keeps its `None`s as nulls
+    let mut seeded = Profile {
+        name: "bob".to_owned(),
+        nickname: None,
+        avatar: None,
+    }
+    .to_builder();
+    assert_eq!(seeded.nickname, ProfileBuilderTristate::Null);
+    assert_eq!(seeded.reset().avatar, ProfileBuilderTristate::Unset);
+}
@@ -0,0 +1,66 @@
+// `#[builder(new)]` adds `Target::new`, taking the required fields in
+// declaration order. When nothing else can fail it returns the target itself,
+// otherwise a builder with those fields set, for the optional ones to follow.
+// A required field with a validated setter makes `new` return a `Result`.
+
+use turann::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(new)]
+pub struct Point {
+    x: i32,
+    y: i32,
+    #[builder(default)]
+    label: String,
+    color: Option<String>,
+}
+
+#[derive(Debug, Builder)]
+#[builder(new, validate = Self::ordered)]
+pub struct Window {
+    start: u32,
+    end: u32,
+    title: Option<String>,
+}
+
+impl WindowBuilder {
+    fn ordered(window: Window) -> Result<Window, WindowBuilderError> {
+        if window.start > window.end {
+            return Err(WindowBuilderError::invalid_state(
+                "start must not come after end",
+            ));
+        }
+
+        Ok(window)
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(new)]
+pub struct Server {
+    host: String,
+    #[builder(range = 1..=65535)]
+    port: u32,
+}
+
+fn main() {
+    let point: Point = Point::new(1, 2);
+    assert_eq!(
+        point,
+        Point {
+            x: 1,
+            y: 2,
+            label: String::new(),
+            color: None,
+        }
+    );
+
+    let mut builder: WindowBuilder = Window::new(1u32, 5u32);
+    let window = builder.title("main".to_owned()).build().unwrap();
+    assert_eq!((window.start, window.end), (1, 5));
+    assert!(Window::new(5u32, 1u32).build().is_err());
+
+    let server = Server::new("localhost", 8080u32).unwrap().build().unwrap();
+    assert_eq!((server.host.as_str(), server.port), ("localhost", 8080));
+    assert!(Server::new("localhost", 0u32).is_err());
+}
@@ -0,0 +1,59 @@
+// `#[builder(impl_default)]` implements `Default` for the target by building
+// it from an empty builder, when no field is required. Defaults declared on
+// the fields are reused rather than written a second time, and validators
+// still run, panicking if the default value does not pass them.
+
+use turann::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(impl_default)]
+pub struct Settings {
+    #[builder(default = Self::default_port)]
+    port: u16,
+    #[builder(default)]
+    verbose: bool,
+    log_file: Option<String>,
+    #[builder(each = "feature")]
+    features: Vec<String>,
+}
+
+impl SettingsBuilder {
+    fn default_port() -> u16 {
+        8080
+    }
+}
+
+#[derive(Debug, Builder)]
+#[builder(impl_default, validate = Self::positive)]
+pub struct Retry {
+    #[builder(default = Self::default_attempts)]
+    attempts: u32,
+}
+
+impl RetryBuilder {
+    fn default_attempts() -> u32 {
+        3
+    }
+
+    fn positive(retry: Retry) -> Result<Retry, RetryBuilderError> {
+        if retry.attempts == 0 {
+            return Err(RetryBuilderError::invalid_state("attempts cannot be zero"));
+        }
+
+        Ok(retry)
+    }
+}
+
+fn main() {
+    assert_eq!(
+        Settings::default(),
+        Settings {
+            port: 8080,
+            verbose: false,
+            log_file: None,
+            features: vec![],
+        }
+    );
+
+    assert_eq!(Retry::default().attempts, 3);
+}
@@ -0,0 +1,15 @@
+// `impl_default` cannot build a value without its required fields, so each
+// of them is reported.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(impl_default)]
+pub struct Connection {
+    host: String,
+    #[builder(default)]
+    port: u16,
+    user: String,
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+// `#[builder(const_fn)]` makes `builder()`, the plain setters, and
+// `build_unchecked()` `const fn`s, so a builder can initialize a `static` or
+// a `const`. It suits `Copy` fields: const setters take the value itself
+// rather than `impl Into`, defaults must come from a `const fn`, and setters
+// which validate or push to a collection stay non-const.
+
+use turann::Builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(const_fn)]
+pub struct Limits {
+    max_connections: u32,
+    #[builder(default = Self::default_timeout)]
+    timeout_ms: u64,
+    burst: Option<u16>,
+}
+
+impl LimitsBuilder {
+    const fn default_timeout() -> u64 {
+        30_000
+    }
+}
+
+static DEFAULT_LIMITS: Limits = {
+    let mut builder = Limits::builder();
+    builder.max_connections(128);
+
+    builder.build_unchecked()
+};
+
+const STRICT_LIMITS: Limits = {
+    let mut builder = Limits::builder();
+    builder.max_connections(8).timeout_ms(1_000).burst(2);
+
+    builder.build_unchecked()
+};
+
+fn main() {
+    assert_eq!(
+        DEFAULT_LIMITS,
+        Limits {
+            max_connections: 128,
+            timeout_ms: 30_000,
+            burst: None,
+        }
+    );
+    assert_eq!(STRICT_LIMITS.burst, Some(2));
+
+    // the regular, checked build is still there
+    assert!(Limits::builder().build().is_err());
+}
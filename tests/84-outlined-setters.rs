@@ -0,0 +1,44 @@
+// An `impl Into<T>` setter is compiled once per type it is called with. Only
+// the `.into()` is generic: the setter forwards to a non-generic function
+// holding the validation and the assignment, which is compiled once. Use
+// #[builder(compact)] to drop the generic conversion altogether.
+
+use std::borrow::Cow;
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Greeting {
+    #[builder(validate = Self::not_blank)]
+    name: String,
+    punctuation: Option<String>,
+}
+
+impl GreetingBuilder {
+    fn not_blank(name: String) -> Result<String, GreetingBuilderError> {
+        if name.trim().is_empty() {
+            return Err(GreetingBuilderError::invalid_field(
+                "name",
+                "cannot be blank",
+            ));
+        }
+
+        Ok(name)
+    }
+}
+
+fn main() {
+    // the same setter with three argument types
+    let mut builder = Greeting::builder();
+    builder.name("borrowed").unwrap();
+    builder.name(String::from("owned")).unwrap();
+    builder.name(Cow::Borrowed("cow")).unwrap();
+    builder.punctuation("!");
+
+    let greeting = builder.build().unwrap();
+    assert_eq!(greeting.name, "cow");
+    assert_eq!(greeting.punctuation.as_deref(), Some("!"));
+
+    // validation still runs behind the conversion
+    assert!(Greeting::builder().name("  ").is_err());
+}
@@ -0,0 +1,98 @@
+// Missing fields are tracked as one bit per field in `build()`, with the
+// names and type names kept in a static table, so a successful build does
+// not allocate to find out that nothing is missing. Structs wider than one
+// word of bits work the same.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(from_str)]
+pub struct Wide {
+    f0: u8,
+    f1: u8,
+    f2: u8,
+    f3: u8,
+    f4: u8,
+    f5: u8,
+    f6: u8,
+    f7: u8,
+    f8: u8,
+    f9: u8,
+    f10: u8,
+    f11: u8,
+    f12: u8,
+    f13: u8,
+    f14: u8,
+    f15: u8,
+    f16: u8,
+    f17: u8,
+    f18: u8,
+    f19: u8,
+    f20: u8,
+    f21: u8,
+    f22: u8,
+    f23: u8,
+    f24: u8,
+    f25: u8,
+    f26: u8,
+    f27: u8,
+    f28: u8,
+    f29: u8,
+    f30: u8,
+    f31: u8,
+    f32: u8,
+    f33: u8,
+    f34: u8,
+    f35: u8,
+    f36: u8,
+    f37: u8,
+    f38: u8,
+    f39: u8,
+    f40: u8,
+    f41: u8,
+    f42: u8,
+    f43: u8,
+    f44: u8,
+    f45: u8,
+    f46: u8,
+    f47: u8,
+    f48: u8,
+    f49: u8,
+    f50: u8,
+    f51: u8,
+    f52: u8,
+    f53: u8,
+    f54: u8,
+    f55: u8,
+    f56: u8,
+    f57: u8,
+    f58: u8,
+    f59: u8,
+    f60: u8,
+    f61: u8,
+    f62: u8,
+    f63: u8,
+    f64: u8,
+    f65: u8,
+}
+
+fn main() {
+    let mut builder = Wide::builder();
+    for index in 0..66 {
+        if index != 1 && index != 65 {
+            builder.set_from_str(&format!("f{index}"), "7").unwrap();
+        }
+    }
+
+    // both sides of the first word boundary are reported, in field order,
+    // followed by hints about the similarly named fields which were set
+    let err = builder.build().unwrap_err().to_string();
+    assert!(
+        err.starts_with("Unable to build Wide: missing required field(s): `f1: u8`, `f65: u8` (")
+    );
+    assert!(err.contains("`f10` was set, did you mean `f1`?"));
+
+    builder.f1(1u8).f65(65u8);
+    let wide = builder.build().unwrap();
+    assert_eq!((wide.f0, wide.f1, wide.f64, wide.f65), (7, 1, 7, 65));
+}
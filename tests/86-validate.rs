@@ -0,0 +1,61 @@
+// `validate()` runs the checks of `build()` which do not need the target:
+// missing fields, collection checks, and pre-validators. It only borrows the
+// builder and constructs nothing, so a form can show whether it is ready to
+// submit on every change. Validators taking the built target still only run
+// in `build()`.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+#[builder(pre_validate = Self::ordered, validate = Self::short)]
+pub struct Booking {
+    guest: String,
+    nights: u32,
+    first_night: Option<u32>,
+    #[builder(each = "room", non_empty)]
+    rooms: Vec<String>,
+}
+
+impl BookingBuilder {
+    fn ordered(builder: &BookingBuilder) -> Result<(), BookingBuilderError> {
+        match builder.get_first_night() {
+            Some(0) => Err(BookingBuilderError::invalid_field(
+                "first_night",
+                "nights are numbered from 1",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn short(booking: Booking) -> Result<Booking, BookingBuilderError> {
+        if booking.nights > 30 {
+            return Err(BookingBuilderError::invalid_state(
+                "stays are limited to 30 nights",
+            ));
+        }
+
+        Ok(booking)
+    }
+}
+
+fn main() {
+    let mut builder = Booking::builder();
+    let err = builder.validate().unwrap_err();
+    assert!(err.to_string().contains("`guest: String`, `nights: u32`"));
+    assert!(err.to_string().contains("cannot be empty"));
+
+    builder
+        .guest("alice".to_owned())
+        .nights(3u32)
+        .room("12".to_owned());
+    assert!(builder.validate().is_ok());
+
+    builder.first_night(0u32);
+    assert!(builder.validate().is_err());
+    builder.first_night(1u32);
+
+    // a target validator is only known once built
+    builder.nights(40u32);
+    assert!(builder.validate().is_ok());
+    assert!(builder.build().is_err());
+}
@@ -0,0 +1,53 @@
+// A default which is expensive to compute, such as one parsed from a file or
+// the environment, can be marked with `cache_default`. It is computed by the
+// first build which needs it and cloned for every later one, instead of being
+// recomputed for each instance.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use turann::Builder;
+
+static COMPUTED: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Builder)]
+pub struct Request {
+    path: String,
+    #[builder(default = Self::load_headers, cache_default)]
+    headers: Vec<(String, String)>,
+    #[builder(default = Self::load_retries)]
+    retries: u32,
+}
+
+impl RequestBuilder {
+    fn load_headers() -> Vec<(String, String)> {
+        COMPUTED.fetch_add(1, Ordering::SeqCst);
+
+        vec![("user-agent".to_owned(), "turann".to_owned())]
+    }
+
+    fn load_retries() -> u32 {
+        3
+    }
+}
+
+fn main() {
+    for index in 0..5 {
+        let request = Request::builder()
+            .path(format!("/items/{index}"))
+            .build()
+            .unwrap();
+
+        assert_eq!(request.headers.len(), 1);
+        assert_eq!(request.retries, 3);
+    }
+
+    // a set field never needs the default
+    let request = Request::builder()
+        .path("/".to_owned())
+        .headers(vec![])
+        .build()
+        .unwrap();
+    assert!(request.headers.is_empty());
+
+    assert_eq!(COMPUTED.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,41 @@
+// The `each` attribute also works on maps. The generated method takes a key
+// and a value and inserts them into the map, replacing any earlier value for
+// the same key.
+
+use std::collections::{BTreeMap, HashMap};
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Request {
+    path: String,
+    #[builder(each = "header")]
+    headers: HashMap<String, String>,
+    #[builder(each = "param")]
+    params: BTreeMap<String, u32>,
+}
+
+fn main() {
+    let request = Request::builder()
+        .path("/items")
+        .header("accept", "application/json")
+        .header("user-agent", "curl")
+        .header("user-agent", "turann")
+        .param("page", 2u32)
+        .param("limit", 50u32)
+        .build()
+        .unwrap();
+
+    assert_eq!(request.headers.len(), 2);
+    assert_eq!(request.headers["accept"], "application/json");
+    assert_eq!(request.headers["user-agent"], "turann");
+    assert_eq!(
+        request.params.into_iter().collect::<Vec<_>>(),
+        vec![("limit".to_owned(), 50), ("page".to_owned(), 2)],
+    );
+
+    // an untouched map is built empty
+    let request = Request::builder().path("/").build().unwrap();
+    assert!(request.headers.is_empty());
+    assert!(request.params.is_empty());
+}
@@ -0,0 +1,33 @@
+// The `each` attribute also works on sets. The generated method inserts the
+// item, so adding the same one twice keeps a single copy.
+
+use std::collections::{BTreeSet, HashSet};
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Post {
+    title: String,
+    #[builder(each = "tag")]
+    tags: HashSet<String>,
+    #[builder(each = "author_id")]
+    author_ids: BTreeSet<u32>,
+}
+
+fn main() {
+    let post = Post::builder()
+        .title("Release notes")
+        .tag("rust")
+        .tag("release")
+        .tag("rust")
+        .author_id(7u32)
+        .author_id(3u32)
+        .author_id(7u32)
+        .build()
+        .unwrap();
+
+    assert_eq!(post.tags.len(), 2);
+    assert!(post.tags.contains("rust"));
+    assert!(post.tags.contains("release"));
+    assert_eq!(post.author_ids.into_iter().collect::<Vec<_>>(), vec![3, 7]);
+}
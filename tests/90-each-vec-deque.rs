@@ -0,0 +1,27 @@
+// The `each` attribute also works on a `VecDeque`. The generated method pushes
+// the item onto the back of the queue, so the queue is built in call order.
+
+use std::collections::VecDeque;
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Pipeline {
+    name: String,
+    #[builder(each = "stage")]
+    stages: VecDeque<String>,
+}
+
+fn main() {
+    let mut pipeline = Pipeline::builder()
+        .name("deploy")
+        .stage("build")
+        .stage("test")
+        .stage("release")
+        .build()
+        .unwrap();
+
+    assert_eq!(pipeline.stages.len(), 3);
+    assert_eq!(pipeline.stages.pop_front().as_deref(), Some("build"));
+    assert_eq!(pipeline.stages.pop_back().as_deref(), Some("release"));
+}
@@ -0,0 +1,72 @@
+// Collections from other crates can take part in `each` by naming the method
+// which adds an item with `insert_with`. Such a collection needs a single type
+// parameter and, like the standard ones, `Default`, `Clone`, `is_empty()` and
+// `clear()`.
+
+use std::collections::BinaryHeap;
+
+use turann::Builder;
+
+#[derive(Debug, Default, Clone)]
+pub struct Bag<T> {
+    items: Vec<(T, usize)>,
+}
+
+impl<T: PartialEq> Bag<T> {
+    fn add(&mut self, item: T) {
+        match self
+            .items
+            .iter_mut()
+            .find(|(existing, _)| *existing == item)
+        {
+            Some((_, count)) => *count += 1,
+            None => self.items.push((item, 1)),
+        }
+    }
+
+    fn count(&self, item: &T) -> usize {
+        self.items
+            .iter()
+            .find(|(existing, _)| existing == item)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+    }
+}
+
+#[derive(Debug, Builder)]
+pub struct Order {
+    customer: String,
+    #[builder(each = "item", insert_with = "add")]
+    items: Bag<String>,
+    #[builder(each = "priority", insert_with = "push")]
+    priorities: BinaryHeap<u8>,
+}
+
+fn main() {
+    let order = Order::builder()
+        .customer("ferris")
+        .item("apple")
+        .item("pear")
+        .item("apple")
+        .priority(2u8)
+        .priority(9u8)
+        .priority(5u8)
+        .build()
+        .unwrap();
+
+    assert_eq!(order.items.count(&"apple".to_owned()), 2);
+    assert_eq!(order.items.count(&"pear".to_owned()), 1);
+    assert_eq!(order.priorities.into_sorted_vec(), vec![2, 5, 9]);
+
+    let mut builder = Order::builder();
+    builder.customer("ferris").item("apple");
+    builder.reset();
+    assert!(!builder.has_items());
+}
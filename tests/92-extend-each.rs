@@ -0,0 +1,42 @@
+// Next to the one-at-a-time `each` method, the builder gets a method named
+// after the field which adds every item of an iterator. If the `each` method
+// already has the field's name, the bulk method is called `extend_<field>`.
+
+use std::collections::HashMap;
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    env: Vec<String>,
+    #[builder(each = "label")]
+    labels: HashMap<String, String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo")
+        .arg("build")
+        .args(["--release", "--locked"])
+        .args(vec!["--offline".to_owned()])
+        .extend_env(["RUST_LOG=debug"])
+        .env("CARGO_TERM_COLOR=never")
+        .labels([("team", "tools"), ("tier", "ci")])
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        command.args,
+        vec!["build", "--release", "--locked", "--offline"]
+    );
+    assert_eq!(
+        command.env,
+        vec!["RUST_LOG=debug", "CARGO_TERM_COLOR=never"]
+    );
+    assert_eq!(command.labels["team"], "tools");
+    assert_eq!(command.labels.len(), 2);
+}
@@ -0,0 +1,25 @@
+// The name of the `each` method can be written as a bare identifier as well
+// as a string literal.
+
+use turann::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = arg)]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    env: Vec<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo")
+        .arg("build")
+        .env("RUST_LOG=debug")
+        .build()
+        .unwrap();
+
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.env, vec!["RUST_LOG=debug"]);
+}
@@ -0,0 +1,54 @@
+// The value checks of a field with `each`, such as `validate`, `check`, or
+// `range`, run on every pushed item. The `each` method returns a `Result`,
+// and the error names the index of the item which failed.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Post {
+    #[builder(each = "tag", validate = Self::lowercase)]
+    tags: Vec<String>,
+    #[builder(each = "port", range = 1..=1024)]
+    ports: Vec<u16>,
+    #[builder(
+        each = "mirror",
+        check(predicate = |url: &String| url.starts_with("https://"), message = "must use https")
+    )]
+    mirrors: Vec<String>,
+}
+
+impl PostBuilder {
+    fn lowercase(value: String) -> Result<String, PostBuilderError> {
+        if value.chars().any(char::is_uppercase) {
+            return Err(PostBuilderError::invalid_field("tags", "must be lowercase"));
+        }
+
+        Ok(value)
+    }
+}
+
+fn field_name(err: PostBuilderError) -> String {
+    match err {
+        PostBuilderError::InvalidField { field_name, .. } => field_name.into_owned(),
+        err => panic!("unexpected error: {err}"),
+    }
+}
+
+fn main() {
+    let mut builder = Post::builder();
+    builder.tag("rust").unwrap().tag("macros").unwrap();
+    assert_eq!(field_name(builder.tag("Derive").unwrap_err()), "tags[2]");
+
+    builder.port(80u16).unwrap();
+    assert_eq!(field_name(builder.port(8080u16).unwrap_err()), "ports[1]");
+
+    assert_eq!(
+        field_name(builder.mirror("http://example.com").unwrap_err()),
+        "mirrors[0]"
+    );
+
+    let post = builder.build();
+    assert_eq!(post.tags, vec!["rust", "macros"]);
+    assert_eq!(post.ports, vec![80]);
+    assert!(post.mirrors.is_empty());
+}
@@ -0,0 +1,50 @@
+// `each` also works on an optional collection. The collection is created by
+// the first item added to it, so a field which is never touched builds to
+// `None` rather than to an empty collection.
+
+use std::collections::BTreeMap;
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Profile {
+    name: String,
+    #[builder(each = "alias", len = 1..=2)]
+    aliases: Option<Vec<String>>,
+    #[builder(each = "link")]
+    links: Option<BTreeMap<String, String>>,
+}
+
+fn main() {
+    let profile = Profile::builder().name("ferris").build().unwrap();
+    assert_eq!(profile.aliases, None);
+    assert_eq!(profile.links, None);
+
+    let profile = Profile::builder()
+        .name("ferris")
+        .alias("crab")
+        .alias("rustacean")
+        .link("home", "https://rust-lang.org")
+        .build()
+        .unwrap();
+    assert_eq!(
+        profile.aliases,
+        Some(vec!["crab".to_owned(), "rustacean".to_owned()])
+    );
+    assert_eq!(profile.links.unwrap()["home"], "https://rust-lang.org");
+
+    // an empty iterator leaves the field untouched
+    let profile = Profile::builder()
+        .name("ferris")
+        .aliases(Vec::<String>::new())
+        .build()
+        .unwrap();
+    assert_eq!(profile.aliases, None);
+
+    let err = Profile::builder()
+        .name("ferris")
+        .aliases(["a", "b", "c"])
+        .build()
+        .unwrap_err();
+    assert!(err.to_string().contains("`aliases`"));
+}
@@ -0,0 +1,45 @@
+// A `Vec` field which is never set builds to an empty `Vec`. Marking it with
+// #[builder(required)] makes leaving it unset a missing-field error instead,
+// so callers have to decide on its contents, even if that is an empty list.
+// A collection with `each` counts as set once an item was added or its bulk
+// method was called.
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Command {
+    executable: String,
+    args: Vec<String>,
+    #[builder(required)]
+    env: Vec<String>,
+    #[builder(each = "mount", required)]
+    mounts: Vec<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo")
+        .env(vec!["RUST_LOG=debug".to_owned()])
+        .mount("/src")
+        .build()
+        .unwrap();
+    assert!(command.args.is_empty());
+    assert_eq!(command.env, vec!["RUST_LOG=debug"]);
+    assert_eq!(command.mounts, vec!["/src"]);
+
+    // an explicitly empty list is fine
+    let command = Command::builder()
+        .executable("cargo")
+        .env(vec![])
+        .mounts(Vec::<String>::new())
+        .build()
+        .unwrap();
+    assert!(command.env.is_empty());
+    assert!(command.mounts.is_empty());
+
+    let err = Command::builder().executable("cargo").build().unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("`env:"), "{message}");
+    assert!(message.contains("`mounts:"), "{message}");
+    assert!(!message.contains("`args:"), "{message}");
+}
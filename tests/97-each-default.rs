@@ -0,0 +1,49 @@
+// A collection with `each` can have a `default` which returns its initial
+// contents. Items added with the `each` method are appended to the default
+// collection, and a field which is never touched builds to the default as is.
+
+use std::collections::HashMap;
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Request {
+    path: String,
+    #[builder(each = "header", default = Self::default_headers)]
+    headers: HashMap<String, String>,
+    #[builder(each = "accept", default = Self::default_accepts)]
+    accepts: Vec<String>,
+}
+
+impl RequestBuilder {
+    fn default_headers() -> HashMap<String, String> {
+        HashMap::from([("user-agent".to_owned(), "turann".to_owned())])
+    }
+
+    fn default_accepts() -> Vec<String> {
+        vec!["text/html".to_owned()]
+    }
+}
+
+fn main() {
+    let request = Request::builder().path("/").build().unwrap();
+    assert_eq!(request.headers["user-agent"], "turann");
+    assert_eq!(request.accepts, vec!["text/html"]);
+
+    let request = Request::builder()
+        .path("/")
+        .header("user-agent", "curl")
+        .header("accept-language", "en")
+        .accept("application/json")
+        .build()
+        .unwrap();
+    assert_eq!(request.headers.len(), 2);
+    assert_eq!(request.headers["user-agent"], "curl");
+    assert_eq!(request.accepts, vec!["text/html", "application/json"]);
+
+    // resetting drops the added items and brings the default back
+    let mut builder = Request::builder();
+    builder.path("/").accept("application/json").reset();
+    let request = builder.path("/").build().unwrap();
+    assert_eq!(request.accepts, vec!["text/html"]);
+}
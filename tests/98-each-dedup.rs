@@ -0,0 +1,39 @@
+// #[builder(dedup)] makes the `each` method skip an item equal to one the
+// collection already holds. With `dedup = "error"` the method returns a
+// `Result` instead, and adding a duplicate fails with an error naming the
+// index the item would have been added at.
+
+use std::collections::VecDeque;
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Post {
+    #[builder(each = "tag", dedup)]
+    tags: Vec<String>,
+    #[builder(each = "feature", dedup = "error")]
+    features: VecDeque<String>,
+}
+
+fn main() {
+    let mut builder = Post::builder();
+    builder.tag("rust").tag("macros").tag("rust");
+    builder.feature("std").unwrap().feature("serde").unwrap();
+
+    let err = builder.feature("std").unwrap_err();
+    match err {
+        PostBuilderError::InvalidField {
+            ref field_name,
+            code,
+            ..
+        } => {
+            assert_eq!(field_name, "features[2]");
+            assert_eq!(code, Some("duplicate"));
+        }
+        err => panic!("unexpected error: {err}"),
+    }
+
+    let post = builder.build();
+    assert_eq!(post.tags, vec!["rust", "macros"]);
+    assert_eq!(post.features, vec!["std", "serde"]);
+}
@@ -0,0 +1,37 @@
+// #[builder(capacity = n)] allocates room for `n` items of an `each`
+// collection when the builder is created, so a builder which is filled in a
+// hot loop does not reallocate as the collection grows.
+
+use std::collections::HashMap;
+
+use turann::Builder;
+
+#[derive(Debug, Builder)]
+pub struct Batch {
+    #[builder(each = "row", capacity = 64)]
+    rows: Vec<u32>,
+    #[builder(each = "label", capacity = 8)]
+    labels: HashMap<String, String>,
+    #[builder(each = "note", capacity = 4)]
+    notes: Option<Vec<String>>,
+}
+
+fn main() {
+    let builder = Batch::builder();
+    assert!(builder.rows.capacity() >= 64);
+    assert!(builder.labels.capacity() >= 8);
+    // an optional collection is only allocated by its first item
+    assert!(builder.notes.is_none());
+
+    let mut builder = Batch::builder();
+    for row in 0..64u32 {
+        builder.row(row);
+    }
+    builder.label("source", "import").note("partial");
+    assert!(builder.notes.as_ref().unwrap().capacity() >= 4);
+
+    let batch = builder.build();
+    assert_eq!(batch.rows.len(), 64);
+    assert_eq!(batch.labels["source"], "import");
+    assert_eq!(batch.notes, Some(vec!["partial".to_owned()]));
+}
@@ -10,4 +10,113 @@ fn tests() {
     t.pass("tests/07-repeated-field.rs");
     t.compile_fail("tests/08-unrecognized-attribute.rs");
     t.pass("tests/09-redefined-prelude-types.rs");
+    t.pass("tests/10-clearable-field.rs");
+    t.pass("tests/11-field-getters.rs");
+    t.pass("tests/12-mutable-field-getters.rs");
+    t.pass("tests/13-field-is-set.rs");
+    t.pass("tests/14-set-any.rs");
+    t.pass("tests/15-set-from-str.rs");
+    t.pass("tests/16-cow-setters.rs");
+    t.pass("tests/17-wrapped-pointer-field.rs");
+    t.pass("tests/21-setter-call-location.rs");
+    t.pass("tests/22-tracing.rs");
+    t.pass("tests/23-compact-setters.rs");
+    t.pass("tests/24-multiple-field-validators.rs");
+    t.pass("tests/25-each-item-validators.rs");
+    t.pass("tests/26-multiple-struct-validators.rs");
+    t.pass("tests/27-pre-build-validator.rs");
+    t.pass("tests/28-finalize-hook.rs");
+    t.pass("tests/29-cross-field-validation.rs");
+    t.pass("tests/30-check-predicate.rs");
+    t.pass("tests/31-range-validation.rs");
+    t.pass("tests/32-len-validation.rs");
+    t.pass("tests/33-non-empty.rs");
+    t.pass("tests/35-validator-crate.rs");
+    t.pass("tests/36-async-validators.rs");
+    t.pass("tests/37-validator-error-conversion.rs");
+    t.pass("tests/38-deferred-setter-errors.rs");
+    t.pass("tests/39-aggregate-build-errors.rs");
+    t.pass("tests/40-treat-empty-as-missing.rs");
+    t.pass("tests/41-at-least-one-of.rs");
+    t.pass("tests/42-conditionally-required.rs");
+    t.pass("tests/43-named-field-validators.rs");
+    t.compile_fail("tests/44-validator-signature.rs");
+    t.pass("tests/45-validate-order.rs");
+    t.pass("tests/46-error-codes.rs");
+    t.pass("tests/48-error-source.rs");
+    t.pass("tests/49-error-derive-and-display.rs");
+    t.pass("tests/50-missing-message.rs");
+    t.pass("tests/51-message-formatter.rs");
+    t.pass("tests/52-missing-field-hints.rs");
+    t.compile_fail("tests/53-conflicting-attributes.rs");
+    t.pass("tests/54-validation-failed.rs");
+    t.pass("tests/55-error-module-path.rs");
+    t.pass("tests/56-error-into.rs");
+    t.pass("tests/57-build-owned.rs");
+    t.pass("tests/58-debug-expansion.rs");
+    t.compile_fail("tests/59-field-types-must-be-clone.rs");
+    t.compile_fail("tests/60-struct-validator-signature.rs");
+    t.compile_fail("tests/61-report-every-attribute-error.rs");
+    t.pass("tests/62-error-report.rs");
+    t.pass("tests/63-typestate.rs");
+    t.compile_fail("tests/64-typestate-missing-field.rs");
+    t.pass("tests/65-owned-build.rs");
+    t.pass("tests/66-take-build.rs");
+    t.pass("tests/67-build-unchecked.rs");
+    t.pass("tests/68-build-or-panic.rs");
+    t.pass("tests/69-infallible-build.rs");
+    t.pass("tests/70-try-from-builder.rs");
+    t.pass("tests/71-build-into-pointers.rs");
+    t.pass("tests/72-build-with-base.rs");
+    t.pass("tests/73-to-builder.rs");
+    t.pass("tests/74-merge-builders.rs");
+    t.pass("tests/75-diff.rs");
+    t.pass("tests/76-checkpoint.rs");
+    t.pass("tests/77-reset.rs");
+    t.pass("tests/78-patch.rs");
+    t.pass("tests/79-tristate.rs");
+    t.pass("tests/80-new.rs");
+    t.pass("tests/81-impl-default.rs");
+    t.compile_fail("tests/82-impl-default-required-field.rs");
+    t.pass("tests/83-const-fn.rs");
+    t.pass("tests/84-outlined-setters.rs");
+    t.pass("tests/85-missing-field-bits.rs");
+    t.pass("tests/86-validate.rs");
+    t.pass("tests/87-cache-default.rs");
+    t.pass("tests/88-each-map.rs");
+    t.pass("tests/89-each-set.rs");
+    t.pass("tests/90-each-vec-deque.rs");
+    t.pass("tests/91-insert-with.rs");
+    t.pass("tests/92-extend-each.rs");
+    t.pass("tests/93-each-ident.rs");
+    t.pass("tests/94-each-value-checks.rs");
+    t.pass("tests/95-each-optional.rs");
+    t.pass("tests/96-required-collections.rs");
+    t.pass("tests/97-each-default.rs");
+    t.pass("tests/98-each-dedup.rs");
+    t.pass("tests/99-capacity.rs");
+    t.pass("tests/100-map-duplicate-keys.rs");
+    t.pass("tests/102-each-string.rs");
+    t.pass("tests/103-each-nested.rs");
+    t.pass("tests/104-map-into.rs");
+    t.pass("tests/105-each-sorted.rs");
+    t.pass("tests/106-garde.rs");
+    t.compile_fail("tests/107-from-str-unparsable-field.rs");
+    t.compile_fail("tests/108-const-fn-hash-map.rs");
+    #[cfg(feature = "regex")]
+    t.pass("tests/34-regex-validation.rs");
+    #[cfg(feature = "serde")]
+    t.pass("tests/47-serialize-errors.rs");
+    #[cfg(feature = "duration")]
+    t.pass("tests/18-duration-setters.rs");
+    #[cfg(feature = "duration")]
+    t.pass("tests/110-foreign-duration.rs");
+    #[cfg(feature = "chrono")]
+    t.pass("tests/19-chrono-timestamp-setters.rs");
+    #[cfg(feature = "time")]
+    t.pass("tests/20-time-timestamp-setters.rs");
+    #[cfg(feature = "smallvec")]
+    t.pass("tests/101-smallvec.rs");
+    #[cfg(feature = "arrayvec")]
+    t.pass("tests/109-arrayvec.rs");
 }